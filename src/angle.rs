@@ -0,0 +1,140 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use num_traits::{Float, FloatConst};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// # [`Angle`]
+/// 角度，内部以弧度表示。
+///
+/// 通过 [`Angle::radians`] 或 [`Angle::degrees`] 构造，从而在类型上区分弧度与角度制的数值。
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Angle<T> {
+    pub radians: T,
+}
+
+impl<T> Angle<T> {
+    /// 以弧度构造。
+    #[inline]
+    pub const fn radians(radians: T) -> Self {
+        Angle { radians }
+    }
+    /// 弧度值。
+    #[inline]
+    pub fn get(self) -> T {
+        self.radians
+    }
+}
+
+impl<T: Float> Angle<T> {
+    /// 以角度制构造。
+    #[inline]
+    pub fn degrees(degrees: T) -> Self {
+        Angle::radians(degrees.to_radians())
+    }
+    /// 角度制的值。
+    #[inline]
+    pub fn to_degrees(self) -> T {
+        self.radians.to_degrees()
+    }
+    #[inline]
+    pub fn zero() -> Self {
+        Angle::radians(T::zero())
+    }
+    #[inline]
+    pub fn sin_cos(self) -> (T, T) {
+        self.radians.sin_cos()
+    }
+}
+
+impl<T: Float + FloatConst> Angle<T> {
+    #[inline]
+    pub fn pi() -> Self {
+        Angle::radians(T::PI())
+    }
+    /// 归一化到 `[0, 2π)`.
+    #[inline]
+    pub fn positive(self) -> Self {
+        let tau = T::TAU();
+        let mut r = self.radians % tau;
+        if r < T::zero() {
+            r = r + tau;
+        }
+        // 极小的负数加上 2π 后可能舍入为 2π.
+        if r >= tau {
+            r = T::zero();
+        }
+        Angle::radians(r)
+    }
+    /// 归一化到 `(-π, π]`, 与 `atan2` 的值域相同。
+    #[inline]
+    pub fn signed(self) -> Self {
+        let r = self.positive().radians;
+        if r > T::PI() {
+            Angle::radians(r - T::TAU())
+        } else {
+            Angle::radians(r)
+        }
+    }
+    /// 从 `self` 转到 `other` 的最小转角，在 `(-π, π]` 之间，逆时针为正。
+    #[inline]
+    pub fn angle_to(self, other: Angle<T>) -> Self {
+        (other - self).signed()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Angle<T> {
+    type Output = Angle<T>;
+    #[inline]
+    fn add(self, rhs: Angle<T>) -> Angle<T> {
+        Angle::radians(self.radians + rhs.radians)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Angle<T> {
+    type Output = Angle<T>;
+    #[inline]
+    fn sub(self, rhs: Angle<T>) -> Angle<T> {
+        Angle::radians(self.radians - rhs.radians)
+    }
+}
+impl<T: Neg<Output = T>> Neg for Angle<T> {
+    type Output = Angle<T>;
+    #[inline]
+    fn neg(self) -> Angle<T> {
+        Angle::radians(-self.radians)
+    }
+}
+impl<T: Mul<Output = T>> Mul<T> for Angle<T> {
+    type Output = Angle<T>;
+    #[inline]
+    fn mul(self, rhs: T) -> Angle<T> {
+        Angle::radians(self.radians * rhs)
+    }
+}
+impl<T: Div<Output = T>> Div<T> for Angle<T> {
+    type Output = Angle<T>;
+    #[inline]
+    fn div(self, rhs: T) -> Angle<T> {
+        Angle::radians(self.radians / rhs)
+    }
+}
@@ -0,0 +1,193 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{Point, Vector};
+use num_traits::Float;
+
+/// 自适应细分计算弧长时的最大递归深度。
+const MAX_DEPTH: u32 = 16;
+
+/// # [`QuadraticBezier`]
+/// 二次贝塞尔曲线，由起点 `from`, 控制点 `ctrl` 与终点 `to` 确定，参数 `t` 在 `[0, 1]` 之间。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct QuadraticBezier<T> {
+    pub from: Point<T>,
+    pub ctrl: Point<T>,
+    pub to: Point<T>,
+}
+
+/// # [`CubicBezier`]
+/// 三次贝塞尔曲线，由起点 `from`, 控制点 `ctrl1`, `ctrl2` 与终点 `to` 确定，参数 `t` 在 `[0, 1]` 之间。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CubicBezier<T> {
+    pub from: Point<T>,
+    pub ctrl1: Point<T>,
+    pub ctrl2: Point<T>,
+    pub to: Point<T>,
+}
+
+impl<T> QuadraticBezier<T> {
+    #[inline]
+    pub const fn new(from: Point<T>, ctrl: Point<T>, to: Point<T>) -> Self {
+        QuadraticBezier { from, ctrl, to }
+    }
+    /// 起点与终点互换，曲线形状不变。
+    #[inline]
+    pub fn reverse(self) -> Self {
+        QuadraticBezier::new(self.to, self.ctrl, self.from)
+    }
+}
+
+impl<T: Float> QuadraticBezier<T> {
+    /// 参数 `t` 处的点，以 de Casteljau 算法计算。
+    #[inline]
+    pub fn eval(&self, t: T) -> Point<T> {
+        let a = self.from.lerp(self.ctrl, t);
+        let b = self.ctrl.lerp(self.to, t);
+        a.lerp(b, t)
+    }
+    /// 参数 `t` 处的导数，即切线方向，长度为参数速度。
+    #[inline]
+    pub fn derivative(&self, t: T) -> Vector<T> {
+        let two = T::one() + T::one();
+        let a = self.ctrl - self.from;
+        let b = self.to - self.ctrl;
+        let s = T::one() - t;
+        (a.map(|c| c * s) + b.map(|c| c * t)).map(|c| c * two)
+    }
+    /// 在参数 `t` 处将曲线分为两段。
+    #[inline]
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let a = self.from.lerp(self.ctrl, t);
+        let b = self.ctrl.lerp(self.to, t);
+        let p = a.lerp(b, t);
+        (
+            QuadraticBezier::new(self.from, a, p),
+            QuadraticBezier::new(p, b, self.to),
+        )
+    }
+    /// 升阶为形状相同的三次贝塞尔曲线。
+    #[inline]
+    pub fn to_cubic(&self) -> CubicBezier<T> {
+        let third = T::one() / (T::one() + T::one() + T::one());
+        let two_thirds = T::one() - third;
+        CubicBezier::new(
+            self.from,
+            self.from.lerp(self.ctrl, two_thirds),
+            self.to.lerp(self.ctrl, two_thirds),
+            self.to,
+        )
+    }
+    /// 弧长的近似值。
+    ///
+    /// 不断二分曲线，直到控制多边形的长度与弦长之差不超过 `tolerance`, 再以二者的加权平均估计每一段的弧长。
+    pub fn arc_length(&self, tolerance: T) -> T {
+        self.arc_length_impl(tolerance, MAX_DEPTH)
+    }
+    fn arc_length_impl(&self, tolerance: T, depth: u32) -> T {
+        let chord = (self.to - self.from).length();
+        let polygon = (self.ctrl - self.from).length() + (self.to - self.ctrl).length();
+        if depth == 0 || polygon - chord <= tolerance {
+            // 对于 n 次曲线，(2 * 弦长 + (n - 1) * 多边形长度) / (n + 1) 的误差远小于二者之差。
+            let two = T::one() + T::one();
+            return (two * chord + polygon) / (two + T::one());
+        }
+        let half = T::one() / (T::one() + T::one());
+        let (a, b) = self.split(half);
+        a.arc_length_impl(tolerance * half, depth - 1)
+            + b.arc_length_impl(tolerance * half, depth - 1)
+    }
+}
+
+impl<T> CubicBezier<T> {
+    #[inline]
+    pub const fn new(from: Point<T>, ctrl1: Point<T>, ctrl2: Point<T>, to: Point<T>) -> Self {
+        CubicBezier {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+    /// 起点与终点互换，曲线形状不变。
+    #[inline]
+    pub fn reverse(self) -> Self {
+        CubicBezier::new(self.to, self.ctrl2, self.ctrl1, self.from)
+    }
+}
+
+impl<T: Float> CubicBezier<T> {
+    /// 参数 `t` 处的点，以 de Casteljau 算法计算。
+    #[inline]
+    pub fn eval(&self, t: T) -> Point<T> {
+        self.split_points(t)[2]
+    }
+    /// 参数 `t` 处的导数，即切线方向，长度为参数速度。
+    #[inline]
+    pub fn derivative(&self, t: T) -> Vector<T> {
+        let three = T::one() + T::one() + T::one();
+        let a = self.ctrl1 - self.from;
+        let b = self.ctrl2 - self.ctrl1;
+        let c = self.to - self.ctrl2;
+        let s = T::one() - t;
+        let (ka, kb, kc) = (s * s, (T::one() + T::one()) * s * t, t * t);
+        (a.map(|v| v * ka) + b.map(|v| v * kb) + c.map(|v| v * kc)).map(|v| v * three)
+    }
+    /// 在参数 `t` 处将曲线分为两段。
+    #[inline]
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let [a, ab, p, bc, c] = self.split_points(t);
+        (
+            CubicBezier::new(self.from, a, ab, p),
+            CubicBezier::new(p, bc, c, self.to),
+        )
+    }
+    /// de Casteljau 算法的中间点，中间的一个即曲线上的点。
+    #[inline]
+    fn split_points(&self, t: T) -> [Point<T>; 5] {
+        let a = self.from.lerp(self.ctrl1, t);
+        let b = self.ctrl1.lerp(self.ctrl2, t);
+        let c = self.ctrl2.lerp(self.to, t);
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        [a, ab, ab.lerp(bc, t), bc, c]
+    }
+    /// 弧长的近似值，见 [`QuadraticBezier::arc_length`].
+    pub fn arc_length(&self, tolerance: T) -> T {
+        self.arc_length_impl(tolerance, MAX_DEPTH)
+    }
+    fn arc_length_impl(&self, tolerance: T, depth: u32) -> T {
+        let chord = (self.to - self.from).length();
+        let polygon = (self.ctrl1 - self.from).length()
+            + (self.ctrl2 - self.ctrl1).length()
+            + (self.to - self.ctrl2).length();
+        let half = T::one() / (T::one() + T::one());
+        if depth == 0 || polygon - chord <= tolerance {
+            return (chord + polygon) * half;
+        }
+        let (a, b) = self.split(half);
+        a.arc_length_impl(tolerance * half, depth - 1)
+            + b.arc_length_impl(tolerance * half, depth - 1)
+    }
+}
@@ -0,0 +1,97 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use num_traits::{Float, FloatConst};
+
+/// 缓动曲线，将 `[0, 1]` 上的参数映射到 `[0, 1]`, 且保持两端点不变。
+///
+/// 可以配合 [`Point::lerp_eased`](crate::point_2d::Point::lerp_eased) 使用。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Easing {
+    /// 匀速。
+    #[default]
+    Linear,
+    /// `t²`.
+    QuadIn,
+    /// `1 - (1 - t)²`.
+    QuadOut,
+    /// 前半段为 `QuadIn`, 后半段为 `QuadOut`.
+    QuadInOut,
+    /// `t³`.
+    CubicIn,
+    /// `1 - (1 - t)³`.
+    CubicOut,
+    /// 前半段为 `CubicIn`, 后半段为 `CubicOut`.
+    CubicInOut,
+    /// `1 - cos(t·π/2)`.
+    SineIn,
+    /// `sin(t·π/2)`.
+    SineOut,
+    /// `(1 - cos(t·π)) / 2`.
+    SineInOut,
+    /// `3t² - 2t³`.
+    SmoothStep,
+}
+
+impl Easing {
+    /// 计算缓动后的参数。`t` 被限制在 `[0, 1]` 之间，`NaN` 视作 0.
+    pub fn apply<T: Float + FloatConst>(self, t: T) -> T {
+        let (zero, one) = (T::zero(), T::one());
+        let t = if t > zero { t.min(one) } else { zero };
+        let two = one + one;
+        let half = one / two;
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => {
+                let s = one - t;
+                one - s * s
+            }
+            Easing::QuadInOut => {
+                if t < half {
+                    two * t * t
+                } else {
+                    let s = two - two * t;
+                    one - s * s / two
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => {
+                let s = one - t;
+                one - s * s * s
+            }
+            Easing::CubicInOut => {
+                if t < half {
+                    two * two * t * t * t
+                } else {
+                    let s = two - two * t;
+                    one - s * s * s / two
+                }
+            }
+            Easing::SineIn => one - (t * T::FRAC_PI_2()).cos(),
+            Easing::SineOut => (t * T::FRAC_PI_2()).sin(),
+            Easing::SineInOut => (one - (t * T::PI()).cos()) / two,
+            Easing::SmoothStep => t * t * (two + one - two * t),
+        }
+    }
+}
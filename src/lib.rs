@@ -20,4 +20,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+pub mod angle;
+pub mod curve_2d;
+pub mod line_2d;
 pub mod point_2d;
+pub mod point_3d;
+pub mod polygon_2d;
+pub mod predicates;
+pub mod rect_2d;
+pub mod size_2d;
+pub mod transform_2d;
@@ -0,0 +1,279 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    line_2d::{project, Segment},
+    point_2d::{get, Point, Point2D, Vector},
+    predicates::{ExactPredicates, Orientation},
+};
+use num_traits::{Float, Num};
+use std::cmp::Ordering;
+
+/// # [`Line`]
+/// 过 `from` 与 `to` 两点的有向直线，方向从 `from` 指向 `to`.
+///
+/// 参数 `t` 处的点为 `from + t * (to - from)`, 两点重合时直线退化为一点。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Line<T> {
+    pub from: Point<T>,
+    pub to: Point<T>,
+}
+
+/// # [`Ray`]
+/// 从 `origin` 出发、经过 `through` 的射线，参数 `t` 不小于 `0`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Ray<T> {
+    pub origin: Point<T>,
+    pub through: Point<T>,
+}
+
+impl<T> Line<T> {
+    /// 两点可以是任意实现了 [`Point2D`] 的类型。
+    #[inline]
+    pub fn new<A: Point2D<T>, B: Point2D<T>>(from: A, to: B) -> Self {
+        Line {
+            from: from.into_point(),
+            to: to.into_point(),
+        }
+    }
+    /// 方向相反的同一直线。
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Line {
+            from: self.to,
+            to: self.from,
+        }
+    }
+    /// 从 `from` 到 `to` 的线段。
+    #[inline]
+    pub fn to_segment(self) -> Segment<T> {
+        Segment::new(self.from, self.to)
+    }
+}
+
+impl<T> Ray<T> {
+    /// 两点可以是任意实现了 [`Point2D`] 的类型。
+    #[inline]
+    pub fn new<A: Point2D<T>, B: Point2D<T>>(origin: A, through: B) -> Self {
+        Ray {
+            origin: origin.into_point(),
+            through: through.into_point(),
+        }
+    }
+    /// 所在的直线，方向与射线相同。
+    #[inline]
+    pub fn to_line(self) -> Line<T> {
+        Line::new(self.origin, self.through)
+    }
+}
+
+impl<T: Num + Copy> Line<T> {
+    /// 过 `p`, 方向为 `direction` 的直线。
+    #[inline]
+    pub fn from_direction<P: Point2D<T>>(p: P, direction: Vector<T>) -> Self {
+        let from = p.into_point();
+        Line {
+            from,
+            to: from + direction,
+        }
+    }
+    /// 方向向量，即 `to - from`.
+    #[inline]
+    pub fn direction(&self) -> Vector<T> {
+        self.to - self.from
+    }
+}
+
+impl<T: Num + Copy> Ray<T> {
+    /// 从 `origin` 出发，方向为 `direction` 的射线。
+    #[inline]
+    pub fn from_direction<P: Point2D<T>>(origin: P, direction: Vector<T>) -> Self {
+        let origin = origin.into_point();
+        Ray {
+            origin,
+            through: origin + direction,
+        }
+    }
+    /// 方向向量，即 `through - origin`.
+    #[inline]
+    pub fn direction(&self) -> Vector<T> {
+        self.through - self.origin
+    }
+}
+
+impl<T: ExactPredicates> Line<T> {
+    /// `p` 在直线的哪一侧，逆时针表示在左侧。结果是精确的。
+    ///
+    /// 直线退化为一点时没有方向，返回 `None`.
+    #[inline]
+    pub fn side<P: Point2D<T>>(&self, p: &P) -> Option<Orientation> {
+        if self.from == self.to {
+            return None;
+        }
+        Some(Orientation::from_sign(T::orient2d(
+            self.from,
+            self.to,
+            get(p),
+        )))
+    }
+    /// `p` 是否在直线上，结果是精确的。直线退化为一点时，只包含该点。
+    #[inline]
+    pub fn contains<P: Point2D<T>>(&self, p: &P) -> bool {
+        match self.side(p) {
+            Some(side) => side == Orientation::Collinear,
+            None => get(p) == self.from,
+        }
+    }
+}
+
+impl<T: ExactPredicates> Ray<T> {
+    /// `p` 在射线所在直线的哪一侧，逆时针表示在左侧。结果是精确的。
+    ///
+    /// 射线退化为一点时没有方向，返回 `None`.
+    #[inline]
+    pub fn side<P: Point2D<T>>(&self, p: &P) -> Option<Orientation> {
+        if self.origin == self.through {
+            return None;
+        }
+        Some(Orientation::from_sign(T::orient2d(
+            self.origin,
+            self.through,
+            get(p),
+        )))
+    }
+    /// `p` 是否在射线上（含起点），结果是精确的。射线退化为一点时，只包含该点。
+    #[inline]
+    pub fn contains<P: Point2D<T>>(&self, p: &P) -> bool {
+        let (o, t, p) = (self.origin, self.through, get(p));
+        if o == t {
+            return p == o;
+        }
+        // 已经共线时，只需判断 `p` 是否在 `origin` 的另一侧。
+        let behind = |o: T, t: T, p: T| (t > o && p < o) || (t < o && p > o);
+        T::orient2d(o, t, p) == Ordering::Equal && !behind(o.x, t.x, p.x) && !behind(o.y, t.y, p.y)
+    }
+}
+
+impl<T: Float> Line<T> {
+    /// 参数 `t` 处的点。
+    #[inline]
+    pub fn eval(&self, t: T) -> Point<T> {
+        self.from.lerp(self.to, t)
+    }
+    /// `p` 在直线上的投影参数，`from` 处为 `0`, `to` 处为 `1`. 直线退化为一点时返回 `None`.
+    #[inline]
+    pub fn project<P: Point2D<T>>(&self, p: &P) -> Option<T> {
+        project(self.from, self.to, get(p))
+    }
+    /// 直线上离 `p` 最近的点，即 `p` 的垂足。
+    #[inline]
+    pub fn closest_point<P: Point2D<T>>(&self, p: &P) -> Point<T> {
+        self.eval(self.project(p).unwrap_or_else(T::zero))
+    }
+    /// `p` 到直线的距离。
+    #[inline]
+    pub fn distance_to<P: Point2D<T>>(&self, p: &P) -> T {
+        (get(p) - self.closest_point(p)).length()
+    }
+    /// 与 `other` 的交点。平行、重合或任一直线退化时返回 `None`.
+    #[inline]
+    pub fn intersection(&self, other: &Line<T>) -> Option<Point<T>> {
+        let r = self.direction();
+        let s = other.direction();
+        let denom = r.cross(s);
+        if denom == T::zero() {
+            return None;
+        }
+        Some(self.eval((other.from - self.from).cross(s) / denom))
+    }
+}
+
+impl<T: Float> Ray<T> {
+    /// 参数 `t` 处的点，`origin` 处为 `0`, `through` 处为 `1`.
+    #[inline]
+    pub fn eval(&self, t: T) -> Point<T> {
+        self.origin.lerp(self.through, t)
+    }
+    /// `p` 在所在直线上的投影参数，不限制为非负。射线退化为一点时返回 `None`.
+    #[inline]
+    pub fn project<P: Point2D<T>>(&self, p: &P) -> Option<T> {
+        project(self.origin, self.through, get(p))
+    }
+    /// 射线上离 `p` 最近的点。
+    #[inline]
+    pub fn closest_point<P: Point2D<T>>(&self, p: &P) -> Point<T> {
+        let t = self.project(p).unwrap_or_else(T::zero);
+        self.eval(t.max(T::zero()))
+    }
+    /// `p` 到射线的距离。
+    #[inline]
+    pub fn distance_to<P: Point2D<T>>(&self, p: &P) -> T {
+        (get(p) - self.closest_point(p)).length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn degenerate_line() {
+        let line: Line<i32> = Line::new((1, 2), (1, 2));
+        assert_eq!(line.side(&(1, 2)), None);
+        assert_eq!(line.side(&(5, 0)), None);
+        assert!(line.contains(&(1, 2)));
+        assert!(!line.contains(&(5, 0)));
+        assert!(!line.contains(&(1, 3)));
+        let line: Line<f64> = Line::new((0.5, 0.5), (0.5, 0.5));
+        assert!(line.contains(&(0.5, 0.5)));
+        assert!(!line.contains(&(1.0, 1.0)));
+        assert_eq!(line.closest_point(&(3.0, 4.0)), Point::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn degenerate_ray() {
+        let ray: Ray<u32> = Ray::new((3, 3), (3, 3));
+        assert_eq!(ray.side(&(9, 0)), None);
+        assert!(ray.contains(&(3, 3)));
+        assert!(!ray.contains(&(4, 4)));
+        assert!(!ray.contains(&(3, 5)));
+        let ray: Ray<f64> = Ray::from_direction((1.0, 1.0), Vector::new(0.0, 0.0));
+        assert!(ray.contains(&(1.0, 1.0)));
+        assert!(!ray.contains(&(2.0, 1.0)));
+    }
+
+    #[test]
+    fn side_and_contains() {
+        let line: Line<i64> = Line::new((0, 0), (2, 1));
+        assert_eq!(line.side(&(0, 1)), Some(Orientation::CounterClockwise));
+        assert_eq!(line.side(&(0, -1)), Some(Orientation::Clockwise));
+        assert_eq!(line.side(&(-4, -2)), Some(Orientation::Collinear));
+        assert!(line.contains(&(-4, -2)));
+        assert!(line.reverse().contains(&(6, 3)));
+        let ray = Ray::new((0i64, 0), (2, 1));
+        assert_eq!(ray.side(&(0, 1)), Some(Orientation::CounterClockwise));
+        assert!(ray.contains(&(6, 3)));
+        assert!(!ray.contains(&(-4, -2)));
+    }
+}
@@ -0,0 +1,72 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod line;
+mod segment;
+mod sweep;
+
+pub use line::*;
+pub use segment::*;
+pub use sweep::*;
+
+use crate::{point_2d::Point, predicates::ExactPredicates};
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// 已知 `p` 与 `a`, `b` 共线，判断 `p` 是否在线段 `ab` 上（含端点）。
+#[inline]
+fn on_segment<T: Copy + PartialOrd>(a: Point<T>, b: Point<T>, p: Point<T>) -> bool {
+    let between = |p: T, a: T, b: T| (a <= p && p <= b) || (b <= p && p <= a);
+    between(p.x, a.x, b.x) && between(p.y, a.y, b.y)
+}
+
+/// 线段 `ab` 与 `cd` 是否有公共点（含端点与共线重叠）。
+pub(crate) fn segments_intersect<T: ExactPredicates>(
+    a: Point<T>,
+    b: Point<T>,
+    c: Point<T>,
+    d: Point<T>,
+) -> bool {
+    let o1 = T::orient2d(a, b, c);
+    let o2 = T::orient2d(a, b, d);
+    let o3 = T::orient2d(c, d, a);
+    let o4 = T::orient2d(c, d, b);
+    if o1 != o2 && o3 != o4 {
+        return true;
+    }
+    (o1 == Ordering::Equal && on_segment(a, b, c))
+        || (o2 == Ordering::Equal && on_segment(a, b, d))
+        || (o3 == Ordering::Equal && on_segment(c, d, a))
+        || (o4 == Ordering::Equal && on_segment(c, d, b))
+}
+
+/// `p` 在过 `a`, `b` 的直线上的投影参数，`a` 处为 `0`, `b` 处为 `1`. `a` 与 `b` 重合时返回 `None`.
+#[inline]
+fn project<T: Float>(a: Point<T>, b: Point<T>, p: Point<T>) -> Option<T> {
+    let d = b - a;
+    let length_sq = d.length_sq();
+    if length_sq == T::zero() {
+        None
+    } else {
+        Some((p - a).dot(d) / length_sq)
+    }
+}
@@ -0,0 +1,361 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    line_2d::{on_segment, project, segments_intersect, Line, Ray},
+    point_2d::{get, Point, Point2D, Vector},
+    predicates::{ExactPredicates, Orientation},
+};
+use num_traits::{Float, Num};
+use std::cmp::Ordering;
+
+/// # [`Segment`]
+/// 线段，由起点 `from` 与终点 `to` 确定，参数 `t` 在 `[0, 1]` 之间。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Segment<T> {
+    pub from: Point<T>,
+    pub to: Point<T>,
+}
+
+/// 两条线段的交集，见 [`Segment::intersection`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum SegmentIntersection<T> {
+    /// 交于一点。
+    Point(Point<T>),
+    /// 共线且重叠于一段，方向与 `self` 相同。
+    Overlap(Segment<T>),
+}
+
+impl<T> Segment<T> {
+    /// 端点可以是任意实现了 [`Point2D`] 的类型。
+    #[inline]
+    pub fn new<A: Point2D<T>, B: Point2D<T>>(from: A, to: B) -> Self {
+        Segment {
+            from: from.into_point(),
+            to: to.into_point(),
+        }
+    }
+    /// 起点与终点互换。
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Segment {
+            from: self.to,
+            to: self.from,
+        }
+    }
+    /// 所在的直线，方向从 `from` 指向 `to`.
+    #[inline]
+    pub fn to_line(self) -> Line<T> {
+        Line::new(self.from, self.to)
+    }
+    /// 从 `from` 出发、经过 `to` 的射线。
+    #[inline]
+    pub fn to_ray(self) -> Ray<T> {
+        Ray::new(self.from, self.to)
+    }
+}
+
+impl<T, A: Point2D<T>, B: Point2D<T>> From<(A, B)> for Segment<T> {
+    #[inline]
+    fn from((from, to): (A, B)) -> Self {
+        Segment::new(from, to)
+    }
+}
+
+impl<T: Num + Copy> Segment<T> {
+    /// 从 `from` 指向 `to` 的向量。
+    #[inline]
+    pub fn to_vector(&self) -> Vector<T> {
+        self.to - self.from
+    }
+    /// 长度的平方。
+    #[inline]
+    pub fn length_sq(&self) -> T {
+        self.to_vector().length_sq()
+    }
+}
+
+impl<T: ExactPredicates> Segment<T> {
+    /// `p` 在线段所在有向直线的哪一侧，逆时针表示在左侧。结果是精确的。
+    #[inline]
+    pub fn side<P: Point2D<T>>(&self, p: &P) -> Orientation {
+        Orientation::from_sign(T::orient2d(self.from, self.to, get(p)))
+    }
+    /// `p` 是否在线段上（含端点），结果是精确的。
+    #[inline]
+    pub fn contains<P: Point2D<T>>(&self, p: &P) -> bool {
+        let p = get(p);
+        T::orient2d(self.from, self.to, p) == Ordering::Equal && on_segment(self.from, self.to, p)
+    }
+    /// 与 `other` 是否有公共点（含端点与共线重叠），结果是精确的。
+    #[inline]
+    pub fn intersects(&self, other: &Segment<T>) -> bool {
+        segments_intersect(self.from, self.to, other.from, other.to)
+    }
+}
+
+impl<T: Float> Segment<T> {
+    #[inline]
+    pub fn length(&self) -> T {
+        self.to_vector().length()
+    }
+    #[inline]
+    pub fn midpoint(&self) -> Point<T> {
+        self.from.midpoint(self.to)
+    }
+    /// 参数 `t` 处的点。
+    #[inline]
+    pub fn eval(&self, t: T) -> Point<T> {
+        self.from.lerp(self.to, t)
+    }
+    /// `p` 在所在直线上的投影参数，`from` 处为 `0`, `to` 处为 `1`, 不限制在 `[0, 1]` 之间。
+    ///
+    /// 线段退化为一点时返回 `None`.
+    #[inline]
+    pub fn project<P: Point2D<T>>(&self, p: &P) -> Option<T> {
+        project(self.from, self.to, get(p))
+    }
+    /// 线段上离 `p` 最近的点。
+    #[inline]
+    pub fn closest_point<P: Point2D<T>>(&self, p: &P) -> Point<T> {
+        let t = self.project(p).unwrap_or_else(T::zero);
+        self.eval(t.max(T::zero()).min(T::one()))
+    }
+    /// `p` 到线段的距离。
+    #[inline]
+    pub fn distance_to<P: Point2D<T>>(&self, p: &P) -> T {
+        (get(p) - self.closest_point(p)).length()
+    }
+}
+
+impl<T: Float + ExactPredicates> Segment<T> {
+    /// 与 `other` 的交集，没有公共点时返回 `None`.
+    ///
+    /// 是否相交、是否共线都以精确谓词判断，端点处的接触与共线重叠直接取端点坐标；
+    /// 只有两线段在内部交叉时交点才需要计算，此时结果会限制在两线段的包围盒内。
+    pub fn intersection(&self, other: &Segment<T>) -> Option<SegmentIntersection<T>> {
+        let (a, b, c, d) = (self.from, self.to, other.from, other.to);
+        let o1 = T::orient2d(a, b, c);
+        let o2 = T::orient2d(a, b, d);
+        let o3 = T::orient2d(c, d, a);
+        let o4 = T::orient2d(c, d, b);
+        let zero = Ordering::Equal;
+        if o1 == zero && o2 == zero && o3 == zero && o4 == zero {
+            return collinear_overlap(*self, *other);
+        }
+        if o1 == zero && on_segment(a, b, c) {
+            return Some(SegmentIntersection::Point(c));
+        }
+        if o2 == zero && on_segment(a, b, d) {
+            return Some(SegmentIntersection::Point(d));
+        }
+        if o3 == zero && on_segment(c, d, a) {
+            return Some(SegmentIntersection::Point(a));
+        }
+        if o4 == zero && on_segment(c, d, b) {
+            return Some(SegmentIntersection::Point(b));
+        }
+        if o1 == o2 || o3 == o4 {
+            return None;
+        }
+        let r = b - a;
+        let s = d - c;
+        let p = self.eval((c - a).cross(s) / r.cross(s));
+        let clamp = |v: T, a: T, b: T, c: T, d: T| {
+            v.max(a.min(b).max(c.min(d))).min(a.max(b).min(c.max(d)))
+        };
+        Some(SegmentIntersection::Point(Point::new(
+            clamp(p.x, a.x, b.x, c.x, d.x),
+            clamp(p.y, a.y, b.y, c.y, d.y),
+        )))
+    }
+}
+
+/// 共线线段的交集。共线的点按 `(x, y)` 的字典序排列即是它们在直线上的顺序。
+fn collinear_overlap<T: PartialOrd + Copy>(
+    s: Segment<T>,
+    other: Segment<T>,
+) -> Option<SegmentIntersection<T>> {
+    let less = |p: Point<T>, q: Point<T>| p.x < q.x || (p.x == q.x && p.y < q.y);
+    let sorted = |s: Segment<T>| {
+        if less(s.to, s.from) {
+            (s.to, s.from)
+        } else {
+            (s.from, s.to)
+        }
+    };
+    let (a, b) = sorted(s);
+    let (c, d) = sorted(other);
+    let lo = if less(a, c) { c } else { a };
+    let hi = if less(b, d) { b } else { d };
+    if less(hi, lo) {
+        None
+    } else if !less(lo, hi) {
+        Some(SegmentIntersection::Point(lo))
+    } else if less(s.to, s.from) {
+        Some(SegmentIntersection::Overlap(Segment { from: hi, to: lo }))
+    } else {
+        Some(SegmentIntersection::Overlap(Segment { from: lo, to: hi }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(a: (f64, f64), b: (f64, f64)) -> Segment<f64> {
+        Segment::new(a, b)
+    }
+
+    fn point(x: f64, y: f64) -> Option<SegmentIntersection<f64>> {
+        Some(SegmentIntersection::Point(Point::new(x, y)))
+    }
+
+    #[test]
+    fn proper_crossing() {
+        let a = seg((0.0, 0.0), (4.0, 4.0));
+        let b = seg((0.0, 4.0), (4.0, 0.0));
+        assert_eq!(a.intersection(&b), point(2.0, 2.0));
+        assert_eq!(b.intersection(&a), point(2.0, 2.0));
+        assert!(a.intersects(&b));
+        // 交点不能精确表示时，结果在两线段的包围盒内。
+        let c = seg((0.0, 0.0), (3.0, 1.0));
+        let d = seg((0.0, 1.0), (1.0, 0.0));
+        let Some(SegmentIntersection::Point(p)) = c.intersection(&d) else {
+            panic!();
+        };
+        assert!((p.x - 0.75).abs() < 1e-15 && (p.y - 0.25).abs() < 1e-15);
+    }
+
+    #[test]
+    fn endpoint_touch() {
+        let a = seg((0.0, 0.0), (2.0, 0.0));
+        assert_eq!(
+            a.intersection(&seg((2.0, 0.0), (3.0, 5.0))),
+            point(2.0, 0.0)
+        );
+        assert_eq!(
+            a.intersection(&seg((1.0, -1.0), (1.0, 0.0))),
+            point(1.0, 0.0)
+        );
+        assert_eq!(
+            seg((1.0, 3.0), (1.0, 0.0)).intersection(&a),
+            point(1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        let a = seg((0.0, 0.0), (4.0, 2.0));
+        let b = seg((6.0, 3.0), (2.0, 1.0));
+        let overlap = |from, to| Some(SegmentIntersection::Overlap(seg(from, to)));
+        assert_eq!(a.intersection(&b), overlap((2.0, 1.0), (4.0, 2.0)));
+        // 方向与 `self` 相同。
+        assert_eq!(b.intersection(&a), overlap((4.0, 2.0), (2.0, 1.0)));
+        assert_eq!(a.intersection(&a), overlap((0.0, 0.0), (4.0, 2.0)));
+        // 竖直线段。
+        let v = seg((1.0, 0.0), (1.0, 3.0));
+        assert_eq!(
+            v.intersection(&seg((1.0, 5.0), (1.0, 2.0))),
+            overlap((1.0, 2.0), (1.0, 3.0))
+        );
+        // 共线且只有一个公共端点时为一点。
+        assert_eq!(
+            a.intersection(&seg((4.0, 2.0), (8.0, 4.0))),
+            point(4.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn collinear_disjoint_and_parallel() {
+        let a = seg((0.0, 0.0), (2.0, 2.0));
+        let collinear = seg((3.0, 3.0), (5.0, 5.0));
+        assert_eq!(a.intersection(&collinear), None);
+        assert!(!a.intersects(&collinear));
+        let parallel = seg((0.0, 1.0), (2.0, 3.0));
+        assert_eq!(a.intersection(&parallel), None);
+        assert!(!a.intersects(&parallel));
+    }
+
+    #[test]
+    fn zero_length_segments() {
+        let a = seg((0.0, 0.0), (2.0, 2.0));
+        let on = seg((1.0, 1.0), (1.0, 1.0));
+        let off = seg((1.0, 0.0), (1.0, 0.0));
+        assert_eq!(a.intersection(&on), point(1.0, 1.0));
+        assert_eq!(on.intersection(&a), point(1.0, 1.0));
+        assert_eq!(a.intersection(&off), None);
+        assert_eq!(on.intersection(&on), point(1.0, 1.0));
+        assert_eq!(on.intersection(&off), None);
+        assert_eq!(on.project(&(5.0, 5.0)), None);
+        assert_eq!(on.closest_point(&(5.0, 5.0)), Point::new(1.0, 1.0));
+        assert_eq!(on.distance_to(&(4.0, 5.0)), 5.0);
+    }
+
+    #[test]
+    fn projection_and_clamping() {
+        let s = seg((0.0, 0.0), (4.0, 0.0));
+        assert_eq!(s.project(&(1.0, 3.0)), Some(0.25));
+        assert_eq!(s.project(&(-2.0, 1.0)), Some(-0.5));
+        assert_eq!(s.project(&(6.0, 1.0)), Some(1.5));
+        assert_eq!(s.closest_point(&(-2.0, 1.0)), Point::new(0.0, 0.0));
+        assert_eq!(s.closest_point(&(6.0, 1.0)), Point::new(4.0, 0.0));
+        assert_eq!(s.closest_point(&(1.0, 3.0)), Point::new(1.0, 0.0));
+        assert_eq!(s.distance_to(&(7.0, 4.0)), 5.0);
+        assert_eq!(s.distance_to(&(2.0, -3.0)), 3.0);
+        // 直线与射线不在两端截断。
+        assert_eq!(s.to_line().closest_point(&(6.0, 1.0)), Point::new(6.0, 0.0));
+        assert_eq!(s.to_ray().closest_point(&(-2.0, 1.0)), Point::new(0.0, 0.0));
+        assert_eq!(s.to_ray().closest_point(&(6.0, 1.0)), Point::new(6.0, 0.0));
+    }
+
+    #[test]
+    fn side_and_contains_are_exact() {
+        let s: Segment<i64> = Segment::new((0, 0), (i64::MAX, i64::MAX - 1));
+        assert_eq!(s.side(&(1, 1)), Orientation::CounterClockwise);
+        assert_eq!(s.side(&(1, 0)), Orientation::Clockwise);
+        let s: Segment<u32> = Segment::new((0, 0), (4, 4));
+        assert!(s.contains(&(2, 2)));
+        assert!(!s.contains(&(5, 5)));
+        let r: Ray<u32> = Ray::new((2, 2), (4, 4));
+        assert!(r.contains(&(9, 9)));
+        assert!(!r.contains(&(1, 1)));
+    }
+
+    #[test]
+    fn line_intersection() {
+        let a = Line::new((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(
+            a.intersection(&Line::new((0.0, 2.0), (1.0, 2.0))),
+            Some(Point::new(2.0, 2.0))
+        );
+        let parallel = Line::from_direction((0.0, 1.0), Vector::new(2.0, 2.0));
+        assert_eq!(a.intersection(&parallel), None);
+        assert_eq!(a.intersection(&a.reverse()), None);
+        let degenerate = Line::new((3.0, 0.0), (3.0, 0.0));
+        assert_eq!(a.intersection(&degenerate), None);
+        assert!(a.contains(&(-5.0, -5.0)));
+        assert_eq!(a.distance_to(&(0.0, 2.0)), 2f64.sqrt());
+    }
+}
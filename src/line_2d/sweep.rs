@@ -0,0 +1,630 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    line_2d::{Segment, SegmentIntersection},
+    point_2d::Point,
+    predicates::{add, expansion_sign, mul, sub, ExactPredicates},
+};
+use num_traits::Float;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashSet},
+    ops::ControlFlow,
+};
+
+/// 所有相交的线段对。
+///
+/// 返回 `(i, j, 交集)`, 其中 `i < j` 为线段在 `segments` 中的序号，交集与
+/// `segments[i].intersection(&segments[j])` 相同。结果按两线段（`(x, y)` 字典序）最小的公共点排列。
+///
+/// 使用 Bentley–Ottmann 扫描线算法。设 `k` 为相交的对数，事件队列的操作共需 `O((n + k) log n)`;
+/// 扫描线以树堆（treap）存储，每个事件的查找、插入与删除的期望时间为 `O(log n)`, 故共需 `O((n + k) log n)`.
+/// 扫描过程中的判断，包括交点之间的先后，都以精确的方法完成，因此不会遗漏或误报；只有报告的交点坐标是舍入后的结果。
+///
+/// `segments` 的元素可以是 [`Segment`], 也可以是两个端点组成的元组，端点可以是任意实现了 `Point2D` 的类型。
+/// 坐标需要能精确地转换为 `f64`, `f32` 与 `f64` 都满足这一点；含 `NaN` 或无穷的线段会被忽略。
+pub fn segment_intersections<T, I>(segments: I) -> Vec<(usize, usize, SegmentIntersection<T>)>
+where
+    T: Float + ExactPredicates,
+    I: IntoIterator,
+    I::Item: Into<Segment<T>>,
+{
+    let mut result = Vec::new();
+    let _ = sweep(segments, |i, j, intersection| {
+        result.push((i, j, intersection));
+        ControlFlow::Continue(())
+    });
+    result
+}
+
+/// 是否存在相交的线段对。
+///
+/// 与 [`segment_intersections`] 相同，但找到第一对后立即返回。
+pub fn has_segment_intersection<T, I>(segments: I) -> bool
+where
+    T: Float + ExactPredicates,
+    I: IntoIterator,
+    I::Item: Into<Segment<T>>,
+{
+    sweep(segments, |_, _, _| ControlFlow::Break(())).is_break()
+}
+
+/// 扫描线使用的线段，端点按 `(x, y)` 字典序排列，坐标转换为 `f64`.
+struct Seg<T> {
+    index: usize,
+    segment: Segment<T>,
+    from: Point<f64>,
+    to: Point<f64>,
+}
+
+impl<T: Float> Seg<T> {
+    fn new(index: usize, segment: Segment<T>) -> Option<Self> {
+        let f = |p: Point<T>| p.map(|v| v.to_f64().filter(|v| v.is_finite())).unwrap();
+        let (a, b) = (f(segment.from)?, f(segment.to)?);
+        let (from, to) = if (b.x, b.y) < (a.x, a.y) {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        Some(Seg {
+            index,
+            segment,
+            from,
+            to,
+        })
+    }
+    #[inline]
+    fn is_degenerate(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+/// 事件点。
+///
+/// 交点的坐标一般不能用浮点数精确表示，故以齐次坐标 `(x / w, y / w)` 表示，各分量为精确的浮点数展开，`w > 0`.
+/// `lo` 与 `hi` 是包含该点的包围盒，用于快速比较；二者相等时，该点就是 `lo`.
+struct Key {
+    lo: Point<f64>,
+    hi: Point<f64>,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    w: Vec<f64>,
+}
+
+/// 精确的差，结果为展开。
+#[inline]
+fn diff(a: f64, b: f64) -> Vec<f64> {
+    sub(&[a], &[b])
+}
+
+/// 精确的二维叉积。
+#[inline]
+fn cross(ax: &[f64], ay: &[f64], bx: &[f64], by: &[f64]) -> Vec<f64> {
+    sub(&mul(ax, by), &mul(ay, bx))
+}
+
+impl Key {
+    fn point(p: Point<f64>) -> Self {
+        Key {
+            lo: p,
+            hi: p,
+            x: vec![p.x],
+            y: vec![p.y],
+            w: vec![1.0],
+        }
+    }
+    /// 两条线段的交点，二者必须在内部交叉且不共线。
+    fn crossing<T>(a: &Seg<T>, b: &Seg<T>) -> Self {
+        let (rx, ry) = (diff(a.to.x, a.from.x), diff(a.to.y, a.from.y));
+        let (sx, sy) = (diff(b.to.x, b.from.x), diff(b.to.y, b.from.y));
+        let (ex, ey) = (diff(b.from.x, a.from.x), diff(b.from.y, a.from.y));
+        // 交点为 `a.from + (n / d) * r`.
+        let mut w = cross(&rx, &ry, &sx, &sy);
+        let n = cross(&ex, &ey, &sx, &sy);
+        let mut x = add(&mul(&[a.from.x], &w), &mul(&n, &rx));
+        let mut y = add(&mul(&[a.from.y], &w), &mul(&n, &ry));
+        if expansion_sign(&w) == Ordering::Less {
+            for v in [&mut x, &mut y, &mut w] {
+                v.iter_mut().for_each(|c| *c = -*c);
+            }
+        }
+        let (ya, yb) = (a.from.y.min(a.to.y), a.from.y.max(a.to.y));
+        let (yc, yd) = (b.from.y.min(b.to.y), b.from.y.max(b.to.y));
+        Key {
+            lo: Point::new(a.from.x.max(b.from.x), ya.max(yc)),
+            hi: Point::new(a.to.x.min(b.to.x), yb.min(yd)),
+            x,
+            y,
+            w,
+        }
+    }
+    #[inline]
+    fn is_point(&self) -> bool {
+        self.lo == self.hi
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let axis = |lo: fn(&Key) -> f64, hi: fn(&Key) -> f64, v: fn(&Key) -> &[f64]| {
+            if hi(self) < lo(other) {
+                Ordering::Less
+            } else if lo(self) > hi(other) {
+                Ordering::Greater
+            } else if lo(self) == hi(self) && lo(other) == hi(other) {
+                Ordering::Equal
+            } else {
+                expansion_sign(&sub(&mul(v(self), &other.w), &mul(v(other), &self.w)))
+            }
+        };
+        axis(|k| k.lo.x, |k| k.hi.x, |k| &k.x).then_with(|| axis(|k| k.lo.y, |k| k.hi.y, |k| &k.y))
+    }
+}
+
+impl PartialOrd for Key {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Key {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Key {}
+
+/// 事件，按事件点排序。只有线段的起点需要记录线段，终点与交点处经过的线段可以从扫描线上找到。
+struct Event {
+    key: Key,
+    start: Option<usize>,
+}
+
+impl Ord for Event {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl PartialOrd for Event {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Event {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Event {}
+
+/// 事件点 `p` 在线段 `s` 所在有向直线的哪一侧，`Greater` 表示在左侧，即线段在该点下方。
+fn side<T>(s: &Seg<T>, p: &Key) -> Ordering {
+    if p.is_point() {
+        return f64::orient2d(s.from, s.to, p.lo);
+    }
+    let (qx, qy) = (diff(s.to.x, s.from.x), diff(s.to.y, s.from.y));
+    let px = sub(&p.x, &mul(&[s.from.x], &p.w));
+    let py = sub(&p.y, &mul(&[s.from.y], &p.w));
+    expansion_sign(&cross(&qx, &qy, &px, &py))
+}
+
+const NIL: usize = usize::MAX;
+
+struct Node {
+    seg: usize,
+    priority: u64,
+    left: usize,
+    right: usize,
+}
+
+/// 扫描线，按在当前事件点之后的位置从下到上排列线段。
+///
+/// 以树堆实现，节点的顺序即线段在扫描线上的顺序，不存储键。
+/// 线段之间的顺序取决于扫描线的位置，故查找时由调用者给出相对于当前事件点的判断。
+struct Status {
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    root: usize,
+    state: u64,
+}
+
+impl Status {
+    fn new() -> Self {
+        Status {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            state: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+    fn alloc(&mut self, seg: usize) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        let node = Node {
+            seg,
+            priority: self.state,
+            left: NIL,
+            right: NIL,
+        };
+        match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = node;
+                i
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+    /// 分为满足 `pred` 的前缀与其余部分，`pred` 在扫描线上必须先真后假。
+    fn split(&mut self, t: usize, pred: &impl Fn(usize) -> bool) -> (usize, usize) {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+        if pred(self.nodes[t].seg) {
+            let (l, r) = self.split(self.nodes[t].right, pred);
+            self.nodes[t].right = l;
+            (t, r)
+        } else {
+            let (l, r) = self.split(self.nodes[t].left, pred);
+            self.nodes[t].left = r;
+            (l, t)
+        }
+    }
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[a].priority > self.nodes[b].priority {
+            self.nodes[a].right = self.merge(self.nodes[a].right, b);
+            a
+        } else {
+            self.nodes[b].left = self.merge(a, self.nodes[b].left);
+            b
+        }
+    }
+    /// 按顺序取出树中的线段，并释放节点。
+    fn take(&mut self, t: usize, out: &mut Vec<usize>) {
+        if t == NIL {
+            return;
+        }
+        let (left, right) = (self.nodes[t].left, self.nodes[t].right);
+        self.take(left, out);
+        out.push(self.nodes[t].seg);
+        self.free.push(t);
+        self.take(right, out);
+    }
+    fn build(&mut self, segs: &[usize]) -> usize {
+        segs.iter().fold(NIL, |t, &s| {
+            let node = self.alloc(s);
+            self.merge(t, node)
+        })
+    }
+    fn first(&self, mut t: usize) -> Option<usize> {
+        while t != NIL && self.nodes[t].left != NIL {
+            t = self.nodes[t].left;
+        }
+        (t != NIL).then(|| self.nodes[t].seg)
+    }
+    fn last(&self, mut t: usize) -> Option<usize> {
+        while t != NIL && self.nodes[t].right != NIL {
+            t = self.nodes[t].right;
+        }
+        (t != NIL).then(|| self.nodes[t].seg)
+    }
+}
+
+/// 经过同一点的两条线段在该点之后的上下顺序，`Less` 表示 `a` 在下方。
+fn cmp_direction<T>(a: &Seg<T>, b: &Seg<T>) -> Ordering {
+    let sign = if a.from == b.from {
+        f64::orient2d(a.from, a.to, b.to)
+    } else {
+        let (ax, ay) = (diff(a.to.x, a.from.x), diff(a.to.y, a.from.y));
+        let (bx, by) = (diff(b.to.x, b.from.x), diff(b.to.y, b.from.y));
+        expansion_sign(&cross(&ax, &ay, &bx, &by))
+    };
+    sign.reverse().then(a.index.cmp(&b.index))
+}
+
+/// 两条线段是否在内部交叉且不共线。其余的相交情形都发生在端点处，不需要额外的事件。
+fn crosses<T>(a: &Seg<T>, b: &Seg<T>) -> bool {
+    let o1 = f64::orient2d(a.from, a.to, b.from);
+    let o2 = f64::orient2d(a.from, a.to, b.to);
+    let o3 = f64::orient2d(b.from, b.to, a.from);
+    let o4 = f64::orient2d(b.from, b.to, a.to);
+    [o1, o2, o3, o4].iter().all(|&o| o != Ordering::Equal) && o1 != o2 && o3 != o4
+}
+
+fn sweep<T, I, F>(segments: I, mut report: F) -> ControlFlow<()>
+where
+    T: Float + ExactPredicates,
+    I: IntoIterator,
+    I::Item: Into<Segment<T>>,
+    F: FnMut(usize, usize, SegmentIntersection<T>) -> ControlFlow<()>,
+{
+    let segs: Vec<Seg<T>> = segments
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, s)| Seg::new(index, s.into()))
+        .collect();
+    let mut queue = BinaryHeap::with_capacity(segs.len() * 2);
+    for (i, s) in segs.iter().enumerate() {
+        queue.push(Reverse(Event {
+            key: Key::point(s.from),
+            start: Some(i),
+        }));
+        if !s.is_degenerate() {
+            queue.push(Reverse(Event {
+                key: Key::point(s.to),
+                start: None,
+            }));
+        }
+    }
+    let mut status = Status::new();
+    let mut scheduled = HashSet::new();
+    let mut reported = HashSet::new();
+    let mut schedule = |queue: &mut BinaryHeap<Reverse<Event>>, a: usize, b: usize, p: &Key| {
+        let pair = (a.min(b), a.max(b));
+        if crosses(&segs[a], &segs[b]) && !scheduled.contains(&pair) {
+            let key = Key::crossing(&segs[a], &segs[b]);
+            if key > *p {
+                scheduled.insert(pair);
+                queue.push(Reverse(Event { key, start: None }));
+            }
+        }
+    };
+    while let Some(Reverse(event)) = queue.pop() {
+        let p = event.key;
+        let mut starts: Vec<usize> = event.start.into_iter().collect();
+        while queue.peek().is_some_and(|Reverse(next)| next.key == p) {
+            let Some(Reverse(next)) = queue.pop() else {
+                break;
+            };
+            starts.extend(next.start);
+        }
+        // 经过该点的线段在扫描线上是连续的一段。
+        let root = status.root;
+        let (below, rest) = status.split(root, &|s| side(&segs[s], &p) == Ordering::Greater);
+        let (at, above) = status.split(rest, &|s| side(&segs[s], &p) == Ordering::Equal);
+        let mut through = Vec::new();
+        status.take(at, &mut through);
+        // 经过该点的线段两两相交。
+        let here: Vec<usize> = through.iter().chain(&starts).copied().collect();
+        for (k, &a) in here.iter().enumerate() {
+            for &b in &here[k + 1..] {
+                let (a, b) = if segs[a].index < segs[b].index {
+                    (&segs[a], &segs[b])
+                } else {
+                    (&segs[b], &segs[a])
+                };
+                if reported.insert((a.index, b.index)) {
+                    if let Some(intersection) = a.segment.intersection(&b.segment) {
+                        report(a.index, b.index, intersection)?;
+                    }
+                }
+            }
+        }
+        // 去掉在该点结束的线段，重新插入经过与从该点开始的线段。
+        let mut after: Vec<usize> = through
+            .into_iter()
+            .filter(|&s| Key::point(segs[s].to) != p)
+            .chain(starts.into_iter().filter(|&s| !segs[s].is_degenerate()))
+            .collect();
+        after.sort_by(|&a, &b| cmp_direction(&segs[a], &segs[b]));
+        let (lower, upper) = (status.last(below), status.first(above));
+        match (after.first(), after.last()) {
+            (Some(&first), Some(&last)) => {
+                if let Some(lower) = lower {
+                    schedule(&mut queue, lower, first, &p);
+                }
+                if let Some(upper) = upper {
+                    schedule(&mut queue, last, upper, &p);
+                }
+            }
+            _ => {
+                if let (Some(lower), Some(upper)) = (lower, upper) {
+                    schedule(&mut queue, lower, upper, &p);
+                }
+            }
+        }
+        let middle = status.build(&after);
+        let root = status.merge(below, middle);
+        status.root = status.merge(root, above);
+    }
+    ControlFlow::Continue(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    type Pairs = BTreeMap<(usize, usize), SegmentIntersection<f64>>;
+
+    fn brute_force(segments: &[Segment<f64>]) -> Pairs {
+        let mut pairs = BTreeMap::new();
+        for (i, a) in segments.iter().enumerate() {
+            for (j, b) in segments.iter().enumerate().skip(i + 1) {
+                if let Some(intersection) = a.intersection(b) {
+                    pairs.insert((i, j), intersection);
+                }
+            }
+        }
+        pairs
+    }
+
+    fn check(segments: &[Segment<f64>]) -> Pairs {
+        let mut pairs = BTreeMap::new();
+        for (i, j, intersection) in segment_intersections(segments.iter().copied()) {
+            assert!(i < j);
+            assert!(
+                pairs.insert((i, j), intersection).is_none(),
+                "重复报告 {i}, {j}"
+            );
+        }
+        assert_eq!(pairs, brute_force(segments), "{segments:?}");
+        assert_eq!(
+            has_segment_intersection(segments.iter().copied()),
+            !pairs.is_empty()
+        );
+        pairs
+    }
+
+    fn seg(a: (i32, i32), b: (i32, i32)) -> Segment<f64> {
+        Segment::new((a.0 as f64, a.1 as f64), (b.0 as f64, b.1 as f64))
+    }
+
+    #[test]
+    fn matches_brute_force_on_integer_grids() {
+        // 小网格上的随机线段包含大量竖直、共线、重叠、共端点与退化的情形。
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = |m: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % m) as i32
+        };
+        for round in 0..3000 {
+            let m = [3, 5, 8, 50][round % 4];
+            let n = next(12) as usize + 1;
+            let segments: Vec<_> = (0..n)
+                .map(|_| seg((next(m), next(m)), (next(m), next(m))))
+                .collect();
+            check(&segments);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_on_many_segments() {
+        // 大量短线段使扫描线同时包含许多线段。
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let segments: Vec<_> = (0..1500)
+            .map(|_| {
+                let (x, y) = (next() * 100.0, next() * 100.0);
+                Segment::new((x, y), (x + next() * 6.0 - 3.0, y + next() * 6.0 - 3.0))
+            })
+            .collect();
+        assert!(!check(&segments).is_empty());
+    }
+
+    #[test]
+    fn vertical_segment() {
+        let segments = [
+            seg((2, 0), (2, 4)),
+            seg((0, 1), (4, 3)),
+            seg((0, 4), (1, 5)),
+        ];
+        let pairs = check(&segments);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(
+            pairs[&(0, 1)],
+            SegmentIntersection::Point(Point::new(2.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        let segments = [
+            seg((0, 0), (4, 4)),
+            seg((6, 6), (2, 2)),
+            seg((5, 5), (7, 7)),
+        ];
+        let pairs = check(&segments);
+        assert_eq!(
+            pairs[&(0, 1)],
+            SegmentIntersection::Overlap(seg((2, 2), (4, 4)))
+        );
+        assert_eq!(
+            pairs[&(1, 2)],
+            SegmentIntersection::Overlap(seg((6, 6), (5, 5)))
+        );
+        assert!(!pairs.contains_key(&(0, 2)));
+    }
+
+    #[test]
+    fn t_junction() {
+        let segments = [
+            seg((0, 0), (4, 0)),
+            seg((2, 0), (2, 3)),
+            seg((3, 1), (3, 3)),
+        ];
+        let pairs = check(&segments);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(
+            pairs[&(0, 1)],
+            SegmentIntersection::Point(Point::new(2.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn zero_length_segment() {
+        let segments = [
+            seg((1, 1), (1, 1)),
+            seg((0, 0), (2, 2)),
+            seg((1, 1), (1, 1)),
+            seg((3, 3), (3, 3)),
+        ];
+        let pairs = check(&segments);
+        assert_eq!(
+            pairs.keys().copied().collect::<Vec<_>>(),
+            [(0, 1), (0, 2), (1, 2)]
+        );
+        assert!(!has_segment_intersection([
+            seg((0, 0), (0, 0)),
+            seg((1, 0), (1, 0))
+        ]));
+    }
+
+    #[test]
+    fn accepts_point_pairs_and_skips_non_finite() {
+        let result = segment_intersections([
+            ((0.0f32, 0.0), (2.0, 2.0)),
+            ((0.0, 2.0), (2.0, f32::NAN)),
+            ((0.0, 2.0), (2.0, 0.0)),
+        ]);
+        assert_eq!(
+            result,
+            [(0, 2, SegmentIntersection::Point(Point::new(1.0, 1.0)))]
+        );
+    }
+}
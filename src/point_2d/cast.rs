@@ -0,0 +1,166 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{Axis, Point};
+use num_traits::{Bounded, Float, NumCast, ToPrimitive, Zero};
+use std::{error::Error, fmt};
+
+/// 坐标数值类型转换失败时返回的错误，记录了转换失败的坐标轴。
+///
+/// 两个坐标都失败时，记录的是 x 轴。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct CastError {
+    axis: Axis,
+}
+impl CastError {
+    #[inline]
+    pub const fn new(axis: Axis) -> Self {
+        CastError { axis }
+    }
+    /// 转换失败的坐标轴。
+    #[inline]
+    pub const fn axis(&self) -> Axis {
+        self.axis
+    }
+}
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} 坐标无法转换为目标数值类型", self.axis)
+    }
+}
+impl Error for CastError {}
+
+/// 浮点坐标转换为其他数值类型前采用的取整方式。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub enum RoundingMode {
+    /// 四舍五入，恰好位于中间时远离零取整。
+    Nearest,
+    /// 向负无穷取整。
+    Floor,
+    /// 向正无穷取整。
+    Ceil,
+    /// 向零取整，与 [`Point::cast`] 的默认行为相同。
+    #[default]
+    Trunc,
+}
+impl RoundingMode {
+    /// 按该方式对单个数值取整。
+    #[inline]
+    pub fn apply<T: Float>(self, v: T) -> T {
+        match self {
+            RoundingMode::Nearest => v.round(),
+            RoundingMode::Floor => v.floor(),
+            RoundingMode::Ceil => v.ceil(),
+            RoundingMode::Trunc => v.trunc(),
+        }
+    }
+}
+
+/// 饱和转换单个数值：超出范围时取目标类型的最值，`NaN` 转换为零，与 `as` 的行为一致。
+pub(crate) fn saturating_cast<T, U>(v: T) -> U
+where
+    T: ToPrimitive + PartialOrd + Zero,
+    U: NumCast + Bounded + Zero,
+{
+    let is_nan = v.partial_cmp(&v).is_none();
+    let is_negative = v < T::zero();
+    match U::from(v) {
+        Some(u) => u,
+        None if is_nan => U::zero(),
+        None if is_negative => U::min_value(),
+        None => U::max_value(),
+    }
+}
+
+impl<T> Point<T> {
+    /// 转换坐标的数值类型，任一坐标无法表示时返回 `None`.
+    ///
+    /// 浮点数转换为整数时向零取整。
+    #[inline]
+    pub fn cast<U: NumCast>(self) -> Option<Point<U>>
+    where
+        T: ToPrimitive,
+    {
+        self.try_cast().ok()
+    }
+    /// 转换坐标的数值类型，失败时返回的错误会指出是哪个坐标无法表示。
+    ///
+    /// 浮点数转换为整数时向零取整。
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Point<U>, CastError>
+    where
+        T: ToPrimitive,
+    {
+        let x = U::from(self.x).ok_or(CastError::new(Axis::X))?;
+        let y = U::from(self.y).ok_or(CastError::new(Axis::Y))?;
+        Ok(Point { x, y })
+    }
+    /// 按给定的方式取整后再转换坐标的数值类型。
+    #[inline]
+    pub fn cast_with<U: NumCast>(self, mode: RoundingMode) -> Result<Point<U>, CastError>
+    where
+        T: Float,
+    {
+        self.map(|v| mode.apply(v)).try_cast()
+    }
+    /// 转换坐标的数值类型，超出范围时取目标类型的最值，`NaN` 转换为零。
+    #[inline]
+    pub fn saturating_cast<U>(self) -> Point<U>
+    where
+        T: ToPrimitive + PartialOrd + Zero,
+        U: NumCast + Bounded + Zero,
+    {
+        self.map(saturating_cast)
+    }
+    /// 各坐标四舍五入，恰好位于中间时远离零取整。
+    #[inline]
+    pub fn round(self) -> Point<T>
+    where
+        T: Float,
+    {
+        self.map(Float::round)
+    }
+    /// 各坐标向负无穷取整。
+    #[inline]
+    pub fn floor(self) -> Point<T>
+    where
+        T: Float,
+    {
+        self.map(Float::floor)
+    }
+    /// 各坐标向正无穷取整。
+    #[inline]
+    pub fn ceil(self) -> Point<T>
+    where
+        T: Float,
+    {
+        self.map(Float::ceil)
+    }
+    /// 各坐标向零取整。
+    #[inline]
+    pub fn trunc(self) -> Point<T>
+    where
+        T: Float,
+    {
+        self.map(Float::trunc)
+    }
+}
@@ -0,0 +1,130 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{PassPoint, Point};
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub, WrappingAdd,
+    WrappingMul, WrappingSub,
+};
+use std::ops::Sub;
+
+macro_rules! impl_checked_ops {
+    ($($(#[$doc:meta])* $name:ident: $trait:ident;)*) => {
+        $(
+            $(#[$doc])*
+            #[inline]
+            pub fn $name<U: PassPoint<T>>(self, rhs: U) -> Option<Point<T>>
+            where
+                T: $trait,
+            {
+                let rhs = rhs.pass();
+                Some(Point {
+                    x: self.x.$name(&rhs.x)?,
+                    y: self.y.$name(&rhs.y)?,
+                })
+            }
+        )*
+    };
+}
+macro_rules! impl_total_ops {
+    ($($(#[$doc:meta])* $name:ident: $trait:ident;)*) => {
+        $(
+            $(#[$doc])*
+            #[inline]
+            pub fn $name<U: PassPoint<T>>(self, rhs: U) -> Point<T>
+            where
+                T: $trait,
+            {
+                let rhs = rhs.pass();
+                Point {
+                    x: self.x.$name(&rhs.x),
+                    y: self.y.$name(&rhs.y),
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_overflowing_ops {
+    ($($(#[$doc:meta])* $name:ident: $trait:ident;)*) => {
+        $(
+            $(#[$doc])*
+            #[inline]
+            pub fn $name<U: PassPoint<T>>(self, rhs: U) -> (Point<T>, bool)
+            where
+                T: $trait,
+            {
+                let rhs = rhs.pass();
+                let (x, x_overflow) = self.x.$name(&rhs.x);
+                let (y, y_overflow) = self.y.$name(&rhs.y);
+                (Point { x, y }, x_overflow || y_overflow)
+            }
+        )*
+    };
+}
+
+/// 按分量进行的整数运算，溢出时的行为与同名的整数方法一致。
+impl<T> Point<T> {
+    impl_checked_ops! {
+        /// 按分量相加，任一分量溢出时返回 `None`.
+        checked_add: CheckedAdd;
+        /// 按分量相减，任一分量溢出时返回 `None`.
+        checked_sub: CheckedSub;
+        /// 按分量相乘，任一分量溢出时返回 `None`.
+        checked_mul: CheckedMul;
+    }
+    impl_total_ops! {
+        /// 按分量相加，溢出时取数值类型的最值。
+        saturating_add: SaturatingAdd;
+        /// 按分量相减，溢出时取数值类型的最值。
+        saturating_sub: SaturatingSub;
+        /// 按分量相乘，溢出时取数值类型的最值。
+        saturating_mul: SaturatingMul;
+        /// 按分量相加，溢出时回绕。
+        wrapping_add: WrappingAdd;
+        /// 按分量相减，溢出时回绕。
+        wrapping_sub: WrappingSub;
+        /// 按分量相乘，溢出时回绕。
+        wrapping_mul: WrappingMul;
+    }
+    impl_overflowing_ops! {
+        /// 按分量相加，溢出时回绕，并返回是否有分量溢出。
+        overflowing_add: OverflowingAdd;
+        /// 按分量相减，溢出时回绕，并返回是否有分量溢出。
+        overflowing_sub: OverflowingSub;
+        /// 按分量相乘，溢出时回绕，并返回是否有分量溢出。
+        overflowing_mul: OverflowingMul;
+    }
+    /// 各分量之差的绝对值，对无符号整数不会溢出。
+    #[inline]
+    pub fn abs_diff<U: PassPoint<T>>(self, rhs: U) -> Point<T>
+    where
+        T: PartialOrd + Sub<Output = T>,
+    {
+        let rhs = rhs.pass();
+        let abs_diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        Point {
+            x: abs_diff(self.x, rhs.x),
+            y: abs_diff(self.y, rhs.y),
+        }
+    }
+}
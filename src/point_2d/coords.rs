@@ -0,0 +1,214 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    angle::Angle,
+    point_2d::{Point, Point2D, Point2DMut},
+};
+use num_traits::{Float, FloatConst};
+use std::ops::Sub;
+
+/// # [`Polar`]
+/// 极坐标，以到原点的距离 `r` 与从 x 轴正方向逆时针转过的角度 `theta` 表示。
+///
+/// 作为 [`Point2D`] 时按 `(r, θ)` 的顺序存取，即 x 坐标对应 `r`, y 坐标对应 `theta` 的弧度值。
+/// 这只是数据的搬运，并不进行坐标变换；与直角坐标之间的换算请使用 [`Polar::from_cartesian`],
+/// [`Polar::to_cartesian`], [`Point::to_polar`] 与 [`Point::from_polar`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Polar<T> {
+    pub r: T,
+    pub theta: Angle<T>,
+}
+
+/// # [`LogPolar`]
+/// 对数极坐标，以距离的自然对数 `rho` 与角度 `theta` 表示。
+///
+/// 作为 [`Point2D`] 时按 `(ρ, θ)` 的顺序存取，与 [`Polar`] 相同。原点对应 `rho` 为负无穷。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct LogPolar<T> {
+    pub rho: T,
+    pub theta: Angle<T>,
+}
+
+macro_rules! impl_polar_point2d {
+    ($name:ident, $r:ident) => {
+        impl<T> Point2D<T> for $name<T> {
+            #[inline]
+            fn rx(&self) -> &T {
+                &self.$r
+            }
+            #[inline]
+            fn ry(&self) -> &T {
+                &self.theta.radians
+            }
+            #[inline]
+            fn into_point(self) -> Point<T> {
+                Point {
+                    x: self.$r,
+                    y: self.theta.radians,
+                }
+            }
+            #[inline]
+            fn from_point(point_2d: Point<T>) -> Self {
+                $name {
+                    $r: point_2d.x,
+                    theta: Angle::radians(point_2d.y),
+                }
+            }
+        }
+        impl<T> Point2DMut<T> for $name<T> {
+            #[inline]
+            fn as_point_mut(&mut self) -> Point<&mut T> {
+                Point {
+                    x: &mut self.$r,
+                    y: &mut self.theta.radians,
+                }
+            }
+        }
+    };
+}
+impl_polar_point2d!(Polar, r);
+impl_polar_point2d!(LogPolar, rho);
+
+impl<T> Polar<T> {
+    #[inline]
+    pub const fn new(r: T, theta: Angle<T>) -> Self {
+        Polar { r, theta }
+    }
+}
+impl<T: Float> Polar<T> {
+    /// 由直角坐标换算。原点的角度为零。
+    #[inline]
+    pub fn from_cartesian<P: Point2D<T>>(p: P) -> Self {
+        let Point { x, y } = p.into_point();
+        Polar {
+            r: x.hypot(y),
+            theta: Angle::radians(y.atan2(x)),
+        }
+    }
+    /// 换算为直角坐标。
+    #[inline]
+    pub fn to_cartesian<P: Point2D<T>>(self) -> P {
+        let (sin, cos) = self.theta.sin_cos();
+        P::from_point(Point {
+            x: self.r * cos,
+            y: self.r * sin,
+        })
+    }
+}
+impl<T: Float + FloatConst> Polar<T> {
+    /// 使 `r` 不为负，`theta` 在 `(-π, π]` 之间，表示的点不变。
+    #[inline]
+    pub fn normalized(self) -> Self {
+        if self.r < T::zero() {
+            Polar {
+                r: -self.r,
+                theta: (self.theta + Angle::pi()).signed(),
+            }
+        } else {
+            Polar {
+                r: self.r,
+                theta: self.theta.signed(),
+            }
+        }
+    }
+}
+
+impl<T> LogPolar<T> {
+    #[inline]
+    pub const fn new(rho: T, theta: Angle<T>) -> Self {
+        LogPolar { rho, theta }
+    }
+}
+impl<T: Float> LogPolar<T> {
+    /// 由直角坐标换算。
+    #[inline]
+    pub fn from_cartesian<P: Point2D<T>>(p: P) -> Self {
+        Polar::from_cartesian(p).into()
+    }
+    /// 换算为直角坐标。
+    #[inline]
+    pub fn to_cartesian<P: Point2D<T>>(self) -> P {
+        Polar::from(self).to_cartesian()
+    }
+}
+impl<T: Float> From<Polar<T>> for LogPolar<T> {
+    #[inline]
+    fn from(p: Polar<T>) -> Self {
+        LogPolar {
+            rho: p.r.ln(),
+            theta: p.theta,
+        }
+    }
+}
+impl<T: Float> From<LogPolar<T>> for Polar<T> {
+    #[inline]
+    fn from(p: LogPolar<T>) -> Self {
+        Polar {
+            r: p.rho.exp(),
+            theta: p.theta,
+        }
+    }
+}
+
+impl<T: Float> Point<T> {
+    /// 换算为极坐标，见 [`Polar::from_cartesian`].
+    #[inline]
+    pub fn to_polar(self) -> Polar<T> {
+        Polar::from_cartesian(self)
+    }
+    /// 由极坐标换算。
+    #[inline]
+    pub fn from_polar(polar: Polar<T>) -> Self {
+        polar.to_cartesian()
+    }
+    /// 换算为对数极坐标，见 [`LogPolar::from_cartesian`].
+    #[inline]
+    pub fn to_log_polar(self) -> LogPolar<T> {
+        LogPolar::from_cartesian(self)
+    }
+    /// 由对数极坐标换算。
+    #[inline]
+    pub fn from_log_polar(log_polar: LogPolar<T>) -> Self {
+        log_polar.to_cartesian()
+    }
+}
+
+impl<T: Sub<Output = T>> Point<T> {
+    /// 由 y 轴向上的坐标系换算到 y 轴向下、高度为 `height` 的屏幕坐标系，x 坐标不变。
+    ///
+    /// 翻转 y 轴后，逆时针方向的角度在屏幕上表现为顺时针。
+    #[inline]
+    pub fn to_screen(self, height: T) -> Point<T> {
+        Point {
+            x: self.x,
+            y: height - self.y,
+        }
+    }
+    /// 由高度为 `height` 的屏幕坐标换算到 y 轴向上的坐标系，是 [`Point::to_screen`] 的逆运算。
+    #[inline]
+    pub fn from_screen<P: Point2D<T>>(p: P, height: T) -> Point<T> {
+        p.into_point().to_screen(height)
+    }
+}
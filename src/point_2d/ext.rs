@@ -0,0 +1,234 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{CastError, ClonedPoint, Metric, Point, Point2D, RoundingMode};
+use num_traits::{Bounded, Float, NumCast, ToPrimitive, Zero};
+
+/// 为所有 [`Point2D`] 实现的扩展 trait, 无需先手动转换为 [`Point`].
+///
+/// `cast` 等函数返回内建 [`Point`]; 若要一步转换为另一种外部点类型，使用
+/// [`Point2DExt::convert_cast`], 如 `p.convert_cast::<glam::IVec2, i32>()`.
+///
+/// 外部类型自身的同名方法优先于扩展 trait 中的方法，如 `euclid` 点类型的
+/// `cast`, `try_cast`, `round` 等，`glam` 向量的 `round`, `floor` 等。
+/// 此时需以 `Point2DExt::cast(&p)` 的形式调用。
+pub trait Point2DExt<T>: Point2D<T> {
+    /// 直接转换为另一种二维点类型，相当于 `self.into_point().into_point_2d()`.
+    #[inline]
+    fn convert<O: Point2D<T>>(self) -> O
+    where
+        Self: Sized,
+    {
+        O::from_point(self.into_point())
+    }
+    /// 转换为另一种二维点类型，同时转换坐标的数值类型。见 [`Point::try_cast`].
+    #[inline]
+    fn convert_cast<O, U>(self) -> Result<O, CastError>
+    where
+        Self: Sized,
+        O: Point2D<U>,
+        T: ToPrimitive,
+        U: NumCast,
+    {
+        self.into_point().try_cast().map(O::from_point)
+    }
+    /// 按给定的度量方式计算到另一点的距离，另一点可以是不同的二维点类型。
+    #[inline]
+    fn distance_with<M: Metric<T>, B: Point2D<T>>(&self, other: &B, metric: M) -> M::Output
+    where
+        Self: Sized,
+    {
+        metric.distance(self, other)
+    }
+    /// 见 [`Point::cast`].
+    #[inline]
+    fn cast<U: NumCast>(&self) -> Option<Point<U>>
+    where
+        T: ToPrimitive + Clone,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).cast()
+    }
+    /// 见 [`Point::try_cast`].
+    #[inline]
+    fn try_cast<U: NumCast>(&self) -> Result<Point<U>, CastError>
+    where
+        T: ToPrimitive + Clone,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).try_cast()
+    }
+    /// 见 [`Point::cast_with`].
+    #[inline]
+    fn cast_with<U: NumCast>(&self, mode: RoundingMode) -> Result<Point<U>, CastError>
+    where
+        T: Float,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).cast_with(mode)
+    }
+    /// 见 [`Point::round`].
+    #[inline]
+    fn round(&self) -> Point<T>
+    where
+        T: Float,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).round()
+    }
+    /// 见 [`Point::floor`].
+    #[inline]
+    fn floor(&self) -> Point<T>
+    where
+        T: Float,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).floor()
+    }
+    /// 见 [`Point::ceil`].
+    #[inline]
+    fn ceil(&self) -> Point<T>
+    where
+        T: Float,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).ceil()
+    }
+    /// 见 [`Point::trunc`].
+    #[inline]
+    fn trunc(&self) -> Point<T>
+    where
+        T: Float,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).trunc()
+    }
+    /// 见 [`Point::saturating_cast`].
+    #[inline]
+    fn saturating_cast<U>(&self) -> Point<U>
+    where
+        T: ToPrimitive + PartialOrd + Zero + Clone,
+        U: NumCast + Bounded + Zero,
+    {
+        ClonedPoint::<T>::cloned(&self.as_point()).saturating_cast()
+    }
+}
+impl<T, P: Point2D<T>> Point2DExt<T> for P {}
+
+/// 批量转换二维点类型，为切片与 [`Vec`] 实现。
+///
+/// 切片会克隆其中的元素，[`Vec`] 则会被消耗。
+pub trait ConvertAll<T> {
+    /// 将每个点转换为另一种二维点类型。见 [`Point2DExt::convert`].
+    fn convert_all<O: Point2D<T>>(self) -> Vec<O>;
+    /// 将每个点转换为另一种二维点类型，同时转换坐标的数值类型。见 [`Point2DExt::convert_cast`].
+    ///
+    /// 遇到第一个无法转换的点时即返回错误。
+    fn convert_cast_all<O, U>(self) -> Result<Vec<O>, CastError>
+    where
+        O: Point2D<U>,
+        T: ToPrimitive,
+        U: NumCast;
+}
+impl<T, P: Point2D<T>> ConvertAll<T> for Vec<P> {
+    #[inline]
+    fn convert_all<O: Point2D<T>>(self) -> Vec<O> {
+        self.into_iter().map(Point2DExt::convert).collect()
+    }
+    #[inline]
+    fn convert_cast_all<O, U>(self) -> Result<Vec<O>, CastError>
+    where
+        O: Point2D<U>,
+        T: ToPrimitive,
+        U: NumCast,
+    {
+        self.into_iter().map(Point2DExt::convert_cast).collect()
+    }
+}
+impl<T, P: Point2D<T> + Clone> ConvertAll<T> for &[P] {
+    #[inline]
+    fn convert_all<O: Point2D<T>>(self) -> Vec<O> {
+        self.iter().cloned().map(Point2DExt::convert).collect()
+    }
+    #[inline]
+    fn convert_cast_all<O, U>(self) -> Result<Vec<O>, CastError>
+    where
+        O: Point2D<U>,
+        T: ToPrimitive,
+        U: NumCast,
+    {
+        self.iter().cloned().map(Point2DExt::convert_cast).collect()
+    }
+}
+impl<T, P: Point2D<T> + Clone> ConvertAll<T> for &Vec<P> {
+    #[inline]
+    fn convert_all<O: Point2D<T>>(self) -> Vec<O> {
+        self.as_slice().convert_all()
+    }
+    #[inline]
+    fn convert_cast_all<O, U>(self) -> Result<Vec<O>, CastError>
+    where
+        O: Point2D<U>,
+        T: ToPrimitive,
+        U: NumCast,
+    {
+        self.as_slice().convert_cast_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding_on_any_point_2d() {
+        let p = (1.5f64, -2.5f64);
+        assert_eq!(p.round(), Point::new(2.0, -3.0));
+        assert_eq!(p.floor(), Point::new(1.0, -3.0));
+        assert_eq!(p.ceil(), Point::new(2.0, -2.0));
+        assert_eq!([1.7f32, -1.7].trunc(), Point::new(1.0, -1.0));
+    }
+
+    #[cfg(all(feature = "euclid", feature = "glam"))]
+    #[test]
+    fn convert_cast_between_foreign_types() {
+        let p = euclid::default::Point2D::new(1.5f64, -2.0);
+        let v: glam::IVec2 = p.convert_cast::<glam::IVec2, i32>().unwrap();
+        assert_eq!(v, glam::IVec2::new(1, -2));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_inherent_rounding_shadows_extension() {
+        let v = glam::Vec2::new(0.5, -0.5);
+        assert_eq!(v.floor(), glam::Vec2::new(0.0, -1.0));
+        assert_eq!(Point2DExt::floor(&v), Point::new(0.0, -1.0));
+        assert_eq!(Point2DExt::round(&v), Point::new(1.0, -1.0));
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn euclid_inherent_cast_shadows_extension() {
+        let p = euclid::default::Point2D::new(3.0f64, 4.0);
+        let inherent: euclid::default::Point2D<i32> = p.cast();
+        assert_eq!(inherent, euclid::default::Point2D::new(3, 4));
+        assert_eq!(Point2DExt::cast::<i32>(&p), Some(Point::new(3, 4)));
+        assert_eq!(Point2DExt::try_cast::<u8>(&p), Ok(Point::new(3, 4)));
+        assert_eq!(
+            Point2DExt::ceil(&euclid::default::Point2D::new(0.1, 0.9)),
+            Point::new(1.0, 1.0)
+        );
+    }
+}
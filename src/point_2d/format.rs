@@ -0,0 +1,360 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{Axis, Point};
+use std::{error::Error, fmt, str::FromStr};
+
+/// 二维点的文本格式。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub enum PointStyle {
+    /// `(x, y)`, 为 [`Point`] 的 [`Display`](fmt::Display) 实现所采用的格式。
+    #[default]
+    Parenthesized,
+    /// `[x, y]`.
+    Bracketed,
+    /// `x,y`.
+    Comma,
+    /// `x y`.
+    Space,
+}
+
+/// 按指定格式显示 [`Point`], 由 [`Point::display`] 构造。
+///
+/// 宽度、精度等格式参数会分别应用于每个坐标。
+#[derive(Copy, Clone, Debug)]
+pub struct PointDisplay<'a, T> {
+    point: &'a Point<T>,
+    style: PointStyle,
+}
+impl<T: fmt::Display> fmt::Display for PointDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (open, sep, close) = match self.style {
+            PointStyle::Parenthesized => ("(", ", ", ")"),
+            PointStyle::Bracketed => ("[", ", ", "]"),
+            PointStyle::Comma => ("", ",", ""),
+            PointStyle::Space => ("", " ", ""),
+        };
+        f.write_str(open)?;
+        self.point.x.fmt(f)?;
+        f.write_str(sep)?;
+        self.point.y.fmt(f)?;
+        f.write_str(close)
+    }
+}
+impl<T> Point<T> {
+    /// 按指定格式显示。
+    #[inline]
+    pub fn display(&self, style: PointStyle) -> PointDisplay<'_, T> {
+        PointDisplay { point: self, style }
+    }
+}
+/// 以 `(x, y)` 的格式显示，宽度、精度等格式参数会分别应用于每个坐标。
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(PointStyle::Parenthesized).fmt(f)
+    }
+}
+
+/// 解析 [`Point`] 失败的原因。
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParsePointErrorKind<E> {
+    /// 输入为空。
+    Empty,
+    /// 缺少与开头匹配的右括号。
+    UnclosedDelimiter(char),
+    /// 右括号与开头的左括号不匹配。
+    MismatchedDelimiter { expected: char, found: char },
+    /// 缺少某个坐标。
+    MissingComponent(Axis),
+    /// 两个坐标之后还有多余的内容。
+    TrailingInput,
+    /// 某个坐标无法解析。
+    InvalidComponent { axis: Axis, error: E },
+}
+
+/// 解析 [`Point`] 失败时返回的错误，包含出错的位置（字节偏移）与原因。
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParsePointError<E> {
+    position: usize,
+    kind: ParsePointErrorKind<E>,
+}
+impl<E> ParsePointError<E> {
+    #[inline]
+    pub const fn new(position: usize, kind: ParsePointErrorKind<E>) -> Self {
+        ParsePointError { position, kind }
+    }
+    /// 出错位置在输入中的字节偏移。
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+    /// 出错的原因。
+    #[inline]
+    pub const fn kind(&self) -> &ParsePointErrorKind<E> {
+        &self.kind
+    }
+}
+impl<E: fmt::Display> fmt::Display for ParsePointError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "第 {} 字节处：", self.position)?;
+        match &self.kind {
+            ParsePointErrorKind::Empty => f.write_str("输入为空"),
+            ParsePointErrorKind::UnclosedDelimiter(c) => write!(f, "缺少 `{c}`"),
+            ParsePointErrorKind::MismatchedDelimiter { expected, found } => {
+                write!(f, "应为 `{expected}`, 实为 `{found}`")
+            }
+            ParsePointErrorKind::MissingComponent(axis) => write!(f, "缺少 {axis} 坐标"),
+            ParsePointErrorKind::TrailingInput => f.write_str("多余的内容"),
+            ParsePointErrorKind::InvalidComponent { axis, error } => {
+                write!(f, "无法解析 {axis} 坐标：{error}")
+            }
+        }
+    }
+}
+impl<E: Error + 'static> Error for ParsePointError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParsePointErrorKind::InvalidComponent { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+fn parse_component<T: FromStr>(
+    part: &str,
+    position: usize,
+    axis: Axis,
+) -> Result<T, ParsePointError<T::Err>> {
+    let position = position + part.len() - part.trim_start().len();
+    let part = part.trim();
+    if part.is_empty() {
+        return Err(ParsePointError::new(
+            position,
+            ParsePointErrorKind::MissingComponent(axis),
+        ));
+    }
+    if let Some(i) = part.find(char::is_whitespace) {
+        return Err(trailing_input(part, position, i));
+    }
+    part.parse().map_err(|error| {
+        ParsePointError::new(
+            position,
+            ParsePointErrorKind::InvalidComponent { axis, error },
+        )
+    })
+}
+
+/// 从 `s[i..]` 开始的多余内容，位置为其中第一个非空白字符。
+fn trailing_input<E>(s: &str, position: usize, i: usize) -> ParsePointError<E> {
+    let rest = &s[i..];
+    ParsePointError::new(
+        position + i + rest.len() - rest.trim_start().len(),
+        ParsePointErrorKind::TrailingInput,
+    )
+}
+
+/// 接受 `x,y`, `(x, y)`, `x y`, `[x, y]` 等格式，坐标周围可以有任意空白。
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = ParsePointError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let start = s.len() - s.trim_start().len();
+        let trimmed = s.trim();
+        let end = start + trimmed.len();
+        let (start, end) = match trimmed.chars().next() {
+            None => return Err(ParsePointError::new(start, ParsePointErrorKind::Empty)),
+            Some(open @ ('(' | '[')) => {
+                let close = if open == '(' { ')' } else { ']' };
+                let Some(i) = s[start + 1..end].find([')', ']']).map(|i| start + 1 + i) else {
+                    return Err(ParsePointError::new(
+                        end,
+                        ParsePointErrorKind::UnclosedDelimiter(close),
+                    ));
+                };
+                let found = if s[i..].starts_with(')') { ')' } else { ']' };
+                if found != close {
+                    return Err(ParsePointError::new(
+                        i,
+                        ParsePointErrorKind::MismatchedDelimiter {
+                            expected: close,
+                            found,
+                        },
+                    ));
+                }
+                if i + 1 < end {
+                    return Err(trailing_input(&s[..end], 0, i + 1));
+                }
+                (start + 1, i)
+            }
+            Some(_) => (start, end),
+        };
+        let inner = &s[start..end];
+        let (x, y, y_position) = match inner.find(',') {
+            Some(i) => {
+                let y = &inner[i + 1..];
+                if let Some(j) = y.find(',') {
+                    return Err(ParsePointError::new(
+                        start + i + 1 + j,
+                        ParsePointErrorKind::TrailingInput,
+                    ));
+                }
+                (&inner[..i], y, start + i + 1)
+            }
+            None => {
+                let x_offset = inner.len() - inner.trim_start().len();
+                match inner[x_offset..].find(char::is_whitespace) {
+                    Some(i) => (
+                        &inner[..x_offset + i],
+                        &inner[x_offset + i..],
+                        start + x_offset + i,
+                    ),
+                    None => (inner, "", end),
+                }
+            }
+        };
+        Ok(Point {
+            x: parse_component(x, start, Axis::X)?,
+            y: parse_component(y, y_position, Axis::Y)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(s: &str) -> (usize, ParsePointErrorKind<std::num::ParseIntError>) {
+        let e = s.parse::<Point<i32>>().unwrap_err();
+        (e.position(), e.kind().clone())
+    }
+
+    #[test]
+    fn parse_accepted_grammars() {
+        let expected = Point::new(1, -2);
+        for s in [
+            "1,-2",
+            " 1 , -2 ",
+            "(1, -2)",
+            " ( 1 ,-2 ) ",
+            "1 -2",
+            "  1\t-2 ",
+            "[1, -2]",
+            "[ 1 , -2 ]",
+        ] {
+            assert_eq!(s.parse::<Point<i32>>(), Ok(expected), "{s:?}");
+        }
+        assert_eq!(
+            "(0.5, 1e3)".parse::<Point<f64>>(),
+            Ok(Point::new(0.5, 1000.0))
+        );
+        let p = Point::new(1.25, -3.0);
+        for style in [
+            PointStyle::Parenthesized,
+            PointStyle::Bracketed,
+            PointStyle::Comma,
+            PointStyle::Space,
+        ] {
+            assert_eq!(p.display(style).to_string().parse(), Ok(p));
+        }
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(kind(""), (0, ParsePointErrorKind::Empty));
+        assert_eq!(kind("   "), (3, ParsePointErrorKind::Empty));
+    }
+
+    #[test]
+    fn parse_unclosed_delimiter() {
+        assert_eq!(
+            kind("(1, 2"),
+            (5, ParsePointErrorKind::UnclosedDelimiter(')'))
+        );
+        assert_eq!(
+            kind("[1, 2 "),
+            (5, ParsePointErrorKind::UnclosedDelimiter(']'))
+        );
+        assert_eq!(kind("("), (1, ParsePointErrorKind::UnclosedDelimiter(')')));
+    }
+
+    #[test]
+    fn parse_mismatched_delimiter() {
+        let mismatched =
+            |expected, found| ParsePointErrorKind::MismatchedDelimiter { expected, found };
+        assert_eq!(kind("(1,2]"), (4, mismatched(')', ']')));
+        assert_eq!(kind("[1, 2)"), (5, mismatched(']', ')')));
+    }
+
+    #[test]
+    fn parse_missing_component() {
+        assert_eq!(
+            kind("1,"),
+            (2, ParsePointErrorKind::MissingComponent(Axis::Y))
+        );
+        assert_eq!(
+            kind(",2"),
+            (0, ParsePointErrorKind::MissingComponent(Axis::X))
+        );
+        assert_eq!(
+            kind("(1)"),
+            (2, ParsePointErrorKind::MissingComponent(Axis::Y))
+        );
+        assert_eq!(
+            kind("[ ]"),
+            (2, ParsePointErrorKind::MissingComponent(Axis::X))
+        );
+        assert_eq!(
+            kind("1"),
+            (1, ParsePointErrorKind::MissingComponent(Axis::Y))
+        );
+    }
+
+    #[test]
+    fn parse_trailing_input() {
+        assert_eq!(kind("(1, 2) x"), (7, ParsePointErrorKind::TrailingInput));
+        assert_eq!(kind("[1, 2]]"), (6, ParsePointErrorKind::TrailingInput));
+        assert_eq!(kind("1,2,3"), (3, ParsePointErrorKind::TrailingInput));
+        assert_eq!(kind("(1, 2, 3)"), (5, ParsePointErrorKind::TrailingInput));
+        assert_eq!(kind("1 2 3"), (4, ParsePointErrorKind::TrailingInput));
+        assert_eq!(kind("1, 2  x"), (6, ParsePointErrorKind::TrailingInput));
+    }
+
+    #[test]
+    fn parse_invalid_component() {
+        let (position, e) = kind("a,2");
+        assert_eq!(position, 0);
+        assert!(matches!(
+            e,
+            ParsePointErrorKind::InvalidComponent { axis: Axis::X, .. }
+        ));
+        let (position, e) = kind("(1,  b)");
+        assert_eq!(position, 5);
+        assert!(matches!(
+            e,
+            ParsePointErrorKind::InvalidComponent { axis: Axis::Y, .. }
+        ));
+        let e = "1, b".parse::<Point<i32>>().unwrap_err();
+        assert!(Error::source(&e).is_some());
+        assert!(e.to_string().starts_with("第 3 字节处"));
+    }
+}
@@ -0,0 +1,73 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    curve_2d::Easing,
+    point_2d::{PassPoint, Point},
+};
+use num_traits::{Float, FloatConst};
+
+impl<T: Float> Point<T> {
+    /// 线性插值：`t` 为 0 时得到 `self`, 为 1 时得到 `other`, 超出 `[0, 1]` 时外插。
+    ///
+    /// 以 `(1 - t) * a + t * b` 计算，两端点处的结果是精确的。
+    #[inline]
+    pub fn lerp<U: PassPoint<T>>(self, other: U, t: T) -> Point<T> {
+        let other = other.pass();
+        let s = T::one() - t;
+        Point {
+            x: s * self.x + t * other.x,
+            y: s * self.y + t * other.y,
+        }
+    }
+    /// 以缓动曲线 `easing` 调整参数后进行线性插值，`t` 被限制在 `[0, 1]` 之间。
+    #[inline]
+    pub fn lerp_eased<U: PassPoint<T>>(self, other: U, t: T, easing: Easing) -> Point<T>
+    where
+        T: FloatConst,
+    {
+        self.lerp(other, easing.apply(t))
+    }
+    /// 两点的中点。
+    #[inline]
+    pub fn midpoint<U: PassPoint<T>>(self, other: U) -> Point<T> {
+        let other = other.pass();
+        let two = T::one() + T::one();
+        Point {
+            x: (self.x + other.x) / two,
+            y: (self.y + other.y) / two,
+        }
+    }
+    /// [`Point::lerp`] 的逆运算：`self` 在从 `from` 到 `to` 的直线上的投影所对应的参数。
+    ///
+    /// `from` 与 `to` 重合时返回 `None`.
+    #[inline]
+    pub fn inverse_lerp<A: PassPoint<T>, B: PassPoint<T>>(self, from: A, to: B) -> Option<T> {
+        let from = from.pass();
+        let d = to.pass() - from;
+        let len_sq = d.length_sq();
+        if len_sq.is_zero() {
+            return None;
+        }
+        Some((self - from).dot(d) / len_sq)
+    }
+}
@@ -29,6 +29,15 @@ macro_rules! impl_point2d {
                 xy(p)
             }
         }
+        impl$(<$t1$(,$other1)*>)? $crate::point_2d::Point2DMut<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)?*)?{
+            #[inline]
+            fn as_point_mut(&mut self) -> $crate::point_2d::Point<&mut $t2> {
+                $crate::point_2d::Point {
+                    x: &mut self.$x,
+                    y: &mut self.$y,
+                }
+            }
+        }
     };
     ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>, $x:tt, $y:tt, $expr:tt$(, $xx:tt, $yy:tt)?) => {
         impl_point2d!(impl<$t$(, $other)*> Trait<$t> for $root $(:: $idents )*<$t$(, $other)*>, $x, $y, $expr$(, $xx, $yy)?);
@@ -0,0 +1,225 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{Axis, CastError, Point, Point2D};
+use num_traits::{ToPrimitive, Zero};
+
+/// 计算距离与面积时使用的加宽数值类型。
+///
+/// 整数坐标在加宽后的有符号类型中计算，故 `u8`, `i16` 等类型的平方距离与叉积不会溢出；
+/// 64 位整数的结果超出 `i128` 时饱和。
+/// 浮点数不加宽。
+pub trait Widen: Copy {
+    /// 加宽后的类型。
+    type Wide: Copy + PartialOrd + Zero;
+    /// 两数之差的绝对值。
+    fn abs_diff_wide(self, other: Self) -> Self::Wide;
+    /// 二维叉积 `a.x * b.y - a.y * b.x`, 整数溢出时饱和。
+    fn wide_cross(a: Point<Self>, b: Point<Self>) -> Self::Wide;
+    /// 加宽类型的加法，整数溢出时饱和。
+    fn wide_add(a: Self::Wide, b: Self::Wide) -> Self::Wide;
+    /// 加宽类型的乘法，整数溢出时饱和。
+    fn wide_mul(a: Self::Wide, b: Self::Wide) -> Self::Wide;
+}
+macro_rules! impl_widen_int {
+    ($($t:ty => $wide:ty),* $(,)?) => {
+        $(
+            impl Widen for $t {
+                type Wide = $wide;
+                #[inline]
+                fn abs_diff_wide(self, other: Self) -> $wide {
+                    self.abs_diff(other) as $wide
+                }
+                #[inline]
+                fn wide_cross(a: Point<Self>, b: Point<Self>) -> $wide {
+                    let (ax, ay, bx, by) = (a.x as $wide, a.y as $wide, b.x as $wide, b.y as $wide);
+                    ax.saturating_mul(by).saturating_sub(ay.saturating_mul(bx))
+                }
+                #[inline]
+                fn wide_add(a: $wide, b: $wide) -> $wide {
+                    a.saturating_add(b)
+                }
+                #[inline]
+                fn wide_mul(a: $wide, b: $wide) -> $wide {
+                    a.saturating_mul(b)
+                }
+            }
+        )*
+    };
+}
+impl_widen_int!(
+    i8 => i64, i16 => i64, u8 => i64, u16 => i64,
+    i32 => i128, i64 => i128, isize => i128,
+    u32 => i128, u64 => i128, usize => i128,
+);
+macro_rules! impl_widen_float {
+    ($($t:ty),*) => {
+        $(
+            impl Widen for $t {
+                type Wide = $t;
+                #[inline]
+                fn abs_diff_wide(self, other: Self) -> $t {
+                    (self - other).abs()
+                }
+                #[inline]
+                fn wide_cross(a: Point<Self>, b: Point<Self>) -> $t {
+                    a.x * b.y - a.y * b.x
+                }
+                #[inline]
+                fn wide_add(a: $t, b: $t) -> $t {
+                    a + b
+                }
+                #[inline]
+                fn wide_mul(a: $t, b: $t) -> $t {
+                    a * b
+                }
+            }
+        )*
+    };
+}
+impl_widen_float!(f32, f64);
+
+/// 两点之间距离的度量方式。
+///
+/// 两点可以是不同的 [`Point2D`] 类型，只要坐标的数值类型相同。
+pub trait Metric<T> {
+    /// 距离的类型。
+    type Output;
+    /// 两点之间的距离。
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> Self::Output;
+}
+
+#[inline]
+fn to_f64<T: ToPrimitive + Copy>(v: &T, axis: Axis) -> Result<f64, CastError> {
+    v.to_f64().ok_or(CastError::new(axis))
+}
+#[inline]
+fn deltas_f64<T, A, B>(a: &A, b: &B) -> Result<(f64, f64), CastError>
+where
+    T: ToPrimitive + Copy,
+    A: Point2D<T>,
+    B: Point2D<T>,
+{
+    Ok((
+        to_f64(a.rx(), Axis::X)? - to_f64(b.rx(), Axis::X)?,
+        to_f64(a.ry(), Axis::Y)? - to_f64(b.ry(), Axis::Y)?,
+    ))
+}
+
+/// 直线距离，以 `f64` 计算。坐标无法转换为 `f64` 时返回错误。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Euclidean;
+impl<T: ToPrimitive + Copy> Metric<T> for Euclidean {
+    type Output = Result<f64, CastError>;
+    #[inline]
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> Self::Output {
+        deltas_f64(a, b).map(|(dx, dy)| dx.hypot(dy))
+    }
+}
+
+/// 直线距离的平方，在 [`Widen::Wide`] 中精确计算。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct SquaredEuclidean;
+impl<T: Widen> Metric<T> for SquaredEuclidean {
+    type Output = T::Wide;
+    #[inline]
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> T::Wide {
+        let dx = a.rx().abs_diff_wide(*b.rx());
+        let dy = a.ry().abs_diff_wide(*b.ry());
+        T::wide_add(T::wide_mul(dx, dx), T::wide_mul(dy, dy))
+    }
+}
+
+/// 曼哈顿距离，即各坐标之差的绝对值之和，在 [`Widen::Wide`] 中计算。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Manhattan;
+impl<T: Widen> Metric<T> for Manhattan {
+    type Output = T::Wide;
+    #[inline]
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> T::Wide {
+        T::wide_add(a.rx().abs_diff_wide(*b.rx()), a.ry().abs_diff_wide(*b.ry()))
+    }
+}
+
+/// 切比雪夫距离，即各坐标之差的绝对值中的较大者，在 [`Widen::Wide`] 中计算。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Chebyshev;
+impl<T: Widen> Metric<T> for Chebyshev {
+    type Output = T::Wide;
+    #[inline]
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> T::Wide {
+        let dx = a.rx().abs_diff_wide(*b.rx());
+        let dy = a.ry().abs_diff_wide(*b.ry());
+        if dy > dx {
+            dy
+        } else {
+            dx
+        }
+    }
+}
+
+/// 闵可夫斯基距离，`p` 为 1 时即曼哈顿距离，为 2 时即直线距离。以 `f64` 计算。
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Minkowski(pub f64);
+impl<T: ToPrimitive + Copy> Metric<T> for Minkowski {
+    type Output = Result<f64, CastError>;
+    #[inline]
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> Self::Output {
+        let p = self.0;
+        deltas_f64(a, b).map(|(dx, dy)| (dx.abs().powf(p) + dy.abs().powf(p)).powf(p.recip()))
+    }
+}
+
+/// 球面上两点之间的大圆距离，点的 x, y 坐标分别为以度表示的经度与纬度。
+///
+/// 结果的单位与 `radius` 相同，默认为地球平均半径（米）。
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Haversine {
+    pub radius: f64,
+}
+impl Haversine {
+    /// 地球平均半径，单位为米。
+    pub const EARTH_RADIUS: f64 = 6_371_008.8;
+    #[inline]
+    pub const fn new(radius: f64) -> Self {
+        Haversine { radius }
+    }
+}
+impl Default for Haversine {
+    #[inline]
+    fn default() -> Self {
+        Haversine::new(Haversine::EARTH_RADIUS)
+    }
+}
+impl<T: ToPrimitive + Copy> Metric<T> for Haversine {
+    type Output = Result<f64, CastError>;
+    #[inline]
+    fn distance<A: Point2D<T>, B: Point2D<T>>(&self, a: &A, b: &B) -> Self::Output {
+        let (lon1, lat1) = (to_f64(a.rx(), Axis::X)?, to_f64(a.ry(), Axis::Y)?);
+        let (lon2, lat2) = (to_f64(b.rx(), Axis::X)?, to_f64(b.ry(), Axis::Y)?);
+        let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (lon2 - lon1).to_radians();
+        let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        Ok(2.0 * self.radius * h.sqrt().min(1.0).asin())
+    }
+}
@@ -20,14 +20,53 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod cast;
+mod checked;
+mod coords;
+mod ext;
+mod format;
+mod lerp;
 mod macros;
+mod metric;
 pub mod points;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod typed;
+mod vector;
 
+pub use cast::*;
+pub use coords::*;
+pub use ext::*;
+pub use format::*;
+pub use metric::*;
 use num_traits::{Num, NumCast};
 use std::{
+    fmt,
     iter::FusedIterator,
-    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+        SubAssign,
+    },
 };
+pub use typed::*;
+pub use vector::*;
+#[cfg(feature = "derive")]
+pub use yapt_derive::Point2D;
+
+/// 二维坐标轴。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Axis::X => f.write_str("x"),
+            Axis::Y => f.write_str("y"),
+        }
+    }
+}
 
 /// 所有类似的二维点类型都应当实现该 trait.
 pub trait Point2D<T> {
@@ -49,6 +88,44 @@ pub trait Point2D<T> {
     }
 }
 
+/// 可以修改坐标的二维点类型应当实现该 trait.
+///
+/// 由于无法通过 `rx_mut` 与 `ry_mut` 同时借用两个坐标，
+/// 需要实现的是 [`Point2DMut::as_point_mut`], 其余函数均由其提供。
+pub trait Point2DMut<T>: Point2D<T> {
+    /// 使用 x, y 坐标的可变引用构造内建 [`Point`] 类型。
+    fn as_point_mut(&mut self) -> Point<&mut T>;
+    /// 获取 x 坐标的可变引用。
+    #[inline]
+    fn rx_mut(&mut self) -> &mut T {
+        self.as_point_mut().x
+    }
+    /// 获取 y 坐标的可变引用。
+    #[inline]
+    fn ry_mut(&mut self) -> &mut T {
+        self.as_point_mut().y
+    }
+    /// 设置 x 坐标。
+    #[inline]
+    fn set_x(&mut self, x: T) {
+        *self.rx_mut() = x;
+    }
+    /// 设置 y 坐标。
+    #[inline]
+    fn set_y(&mut self, y: T) {
+        *self.ry_mut() = y;
+    }
+}
+
+/// 复制出坐标值。
+#[inline]
+pub(crate) fn get<T: Copy, P: Point2D<T>>(p: &P) -> Point<T> {
+    Point {
+        x: *p.rx(),
+        y: *p.ry(),
+    }
+}
+
 impl<T> Point2D<T> for (T, T) {
     #[inline]
     fn rx(&self) -> &T {
@@ -70,6 +147,15 @@ impl<T> Point2D<T> for (T, T) {
         (point_2d.x, point_2d.y)
     }
 }
+impl<T> Point2DMut<T> for (T, T) {
+    #[inline]
+    fn as_point_mut(&mut self) -> Point<&mut T> {
+        Point {
+            x: &mut self.0,
+            y: &mut self.1,
+        }
+    }
+}
 impl<T> Point2D<T> for [T; 2] {
     #[inline]
     fn rx(&self) -> &T {
@@ -89,6 +175,13 @@ impl<T> Point2D<T> for [T; 2] {
         [point_2d.x, point_2d.y]
     }
 }
+impl<T> Point2DMut<T> for [T; 2] {
+    #[inline]
+    fn as_point_mut(&mut self) -> Point<&mut T> {
+        let [x, y] = self;
+        Point { x, y }
+    }
+}
 /// # [`Point`]
 /// 内建 `Point` 类型，用作二维点类型之间转换的的桥梁。
 ///
@@ -97,6 +190,7 @@ impl<T> Point2D<T> for [T; 2] {
 ///
 /// 这些函数都是各自的成员函数，可以方便地采用链式调用风格。
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Point<T> {
     pub x: T,
     pub y: T,
@@ -119,6 +213,12 @@ impl<T> Point2D<T> for Point<T> {
         point_2d
     }
 }
+impl<T> Point2DMut<T> for Point<T> {
+    #[inline]
+    fn as_point_mut(&mut self) -> Point<&mut T> {
+        self.as_mut()
+    }
+}
 
 pub trait CopiedPoint<T>
 where
@@ -255,7 +355,7 @@ impl<T> Point<&mut T> {
         }
     }
     #[inline]
-    pub fn sub_assign<U: PassPoint<T>>(&mut self, rhs: U)
+    pub fn sub_assign<U: PassVector<T>>(&mut self, rhs: U)
     where
         T: SubAssign,
     {
@@ -264,7 +364,7 @@ impl<T> Point<&mut T> {
         *self.y -= rhs.y;
     }
     #[inline]
-    pub fn add_assign<U: PassPoint<T>>(&mut self, rhs: U)
+    pub fn add_assign<U: PassVector<T>>(&mut self, rhs: U)
     where
         T: AddAssign,
     {
@@ -272,6 +372,33 @@ impl<T> Point<&mut T> {
         *self.x += rhs.x;
         *self.y += rhs.y;
     }
+    #[inline]
+    pub fn mul_assign<U: PassPoint<T>>(&mut self, rhs: U)
+    where
+        T: MulAssign,
+    {
+        let rhs = rhs.pass();
+        *self.x *= rhs.x;
+        *self.y *= rhs.y;
+    }
+    #[inline]
+    pub fn div_assign<U: PassPoint<T>>(&mut self, rhs: U)
+    where
+        T: DivAssign,
+    {
+        let rhs = rhs.pass();
+        *self.x /= rhs.x;
+        *self.y /= rhs.y;
+    }
+    #[inline]
+    pub fn rem_assign<U: PassPoint<T>>(&mut self, rhs: U)
+    where
+        T: RemAssign,
+    {
+        let rhs = rhs.pass();
+        *self.x %= rhs.x;
+        *self.y %= rhs.y;
+    }
 }
 impl<T> Point<Option<T>> {
     #[inline]
@@ -386,6 +513,14 @@ impl<T> Point<T> {
     {
         O::from_point(self.as_ref())
     }
+    /// 转换为从原点指向该点的 [`Vector`].
+    #[inline]
+    pub fn to_vector(self) -> Vector<T> {
+        Vector {
+            x: self.x,
+            y: self.y,
+        }
+    }
     #[inline]
     pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Point<U> {
         Point {
@@ -400,6 +535,78 @@ impl<T> Point<T> {
             inner: IterInner(Some(x), Some(y)),
         }
     }
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let Point { x, y } = self;
+        IterMut {
+            inner: IterInner(Some(x), Some(y)),
+        }
+    }
+    /// 从恰好产生两个元素的迭代器构造，元素个数不为二时返回 `None`.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Option<Point<T>> {
+        let mut iter = iter.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(x), Some(y), None) => Some(Point { x, y }),
+            _ => None,
+        }
+    }
+    /// 依次以 x, y 坐标折叠。
+    #[inline]
+    pub fn fold<B, F: FnMut(B, T) -> B>(self, init: B, mut f: F) -> B {
+        let b = f(init, self.x);
+        f(b, self.y)
+    }
+    /// 是否存在满足条件的坐标。
+    #[inline]
+    pub fn any<F: FnMut(T) -> bool>(self, mut f: F) -> bool {
+        f(self.x) || f(self.y)
+    }
+    /// 是否所有坐标都满足条件。
+    #[inline]
+    pub fn all<F: FnMut(T) -> bool>(self, mut f: F) -> bool {
+        f(self.x) && f(self.y)
+    }
+    /// 较小的坐标。仅当 y 严格小于 x 时返回 y, 故无法比较时（如 `NaN`）返回 x.
+    #[inline]
+    pub fn min_component(self) -> T
+    where
+        T: PartialOrd,
+    {
+        if self.y < self.x {
+            self.y
+        } else {
+            self.x
+        }
+    }
+    /// 较大的坐标。仅当 y 严格大于 x 时返回 y, 故无法比较时（如 `NaN`）返回 x.
+    #[inline]
+    pub fn max_component(self) -> T
+    where
+        T: PartialOrd,
+    {
+        if self.y > self.x {
+            self.y
+        } else {
+            self.x
+        }
+    }
+    /// 坐标之和。
+    #[inline]
+    pub fn sum(self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.x + self.y
+    }
+    /// 坐标之积。
+    #[inline]
+    pub fn product(self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.x * self.y
+    }
 }
 #[derive(Clone, Debug)]
 struct IterInner<A>(Option<A>, Option<A>);
@@ -556,6 +763,24 @@ impl<T> IntoIterator for Point<T> {
         }
     }
 }
+impl<'a, T> IntoIterator for &'a Point<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a mut Point<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
 
 impl<A> DoubleEndedIterator for IntoIter<A> {
     #[inline]
@@ -570,51 +795,267 @@ impl<A> FusedIterator for IntoIter<A> {}
 
 // unsafe impl<A> TrustedLen for IntoIter<A> {}
 
-impl<T: Num, U: PassPoint<T>> Add<U> for Point<T> {
+/// 点与点相减得到 [`Vector`], 点与向量相加减得到 [`Point`].
+///
+/// 点与点相加在仿射意义上并无意义，需要启用 `point-add` feature 才能使用。
+/// 点与点相减的结果是向量，故不为点实现 `SubAssign<Point>`, 只能减去向量。
+///
+/// 由于需要同时接受点与向量作为右操作数，无法像其他运算符一样使用 [`PassPoint`] 泛型，
+/// 故为 [`PassPoint`] 与 [`PassVector`] 的每个实现分别实现。
+macro_rules! impl_point_ops {
+    ($([$($g:tt)*] $rhs:ty),* $(,)?) => {
+        $(
+            impl<$($g)*> Sub<$rhs> for Point<T> {
+                type Output = Vector<T>;
+                #[inline]
+                fn sub(self, other: $rhs) -> Vector<T> {
+                    let other = other.pass();
+                    Vector::new(self.x - other.x, self.y - other.y)
+                }
+            }
+            #[cfg(feature = "point-add")]
+            impl<$($g)*> Add<$rhs> for Point<T> {
+                type Output = Self;
+                #[inline]
+                fn add(self, other: $rhs) -> Point<T> {
+                    let other = other.pass();
+                    (self.x + other.x, self.y + other.y).into_point()
+                }
+            }
+            #[cfg(feature = "point-add")]
+            impl<$($g)*> AddAssign<$rhs> for Point<T>
+            where
+                T: AddAssign<T>,
+            {
+                #[inline]
+                fn add_assign(&mut self, rhs: $rhs) {
+                    let rhs = rhs.pass();
+                    self.x += rhs.x;
+                    self.y += rhs.y;
+                }
+            }
+        )*
+    };
+}
+impl_point_ops!(
+    [T: Num] Point<T>,
+    ['a, T: Num + Copy] &'a Point<T>,
+    ['a, T: Num + Copy] Point<&'a T>,
+    ['a, T: Num + Copy] Point<&'a mut T>,
+);
+macro_rules! impl_point_vector_ops {
+    ($([$($g:tt)*] $rhs:ty),* $(,)?) => {
+        $(
+            impl<$($g)*> Add<$rhs> for Point<T> {
+                type Output = Self;
+                #[inline]
+                fn add(self, other: $rhs) -> Point<T> {
+                    let other = other.pass();
+                    (self.x + other.x, self.y + other.y).into_point()
+                }
+            }
+            impl<$($g)*> AddAssign<$rhs> for Point<T>
+            where
+                T: AddAssign<T>,
+            {
+                #[inline]
+                fn add_assign(&mut self, rhs: $rhs) {
+                    let rhs = rhs.pass();
+                    self.x += rhs.x;
+                    self.y += rhs.y;
+                }
+            }
+            impl<$($g)*> Sub<$rhs> for Point<T> {
+                type Output = Self;
+                #[inline]
+                fn sub(self, other: $rhs) -> Point<T> {
+                    let other = other.pass();
+                    (self.x - other.x, self.y - other.y).into_point()
+                }
+            }
+            /// 暂时无法为 `Point<&mut T>` 实现 `SubAssign`.
+            /// 故将其放在成员函数当中。
+            impl<$($g)*> SubAssign<$rhs> for Point<T>
+            where
+                T: SubAssign<T>,
+            {
+                #[inline]
+                fn sub_assign(&mut self, rhs: $rhs) {
+                    let rhs = rhs.pass();
+                    self.x -= rhs.x;
+                    self.y -= rhs.y;
+                }
+            }
+        )*
+    };
+}
+impl_point_vector_ops!(
+    [T: Num] Vector<T>,
+    ['a, T: Num + Copy] &'a Vector<T>,
+    ['a, T: Num + Copy] Vector<&'a T>,
+    ['a, T: Num + Copy] Vector<&'a mut T>,
+);
+impl<T: Num, U: PassPoint<T>> Mul<U> for Point<T> {
     type Output = Self;
     #[inline]
-    fn add(self, other: U) -> Point<T> {
+    fn mul(self, other: U) -> Point<T> {
         let other = other.pass();
-        (self.x + other.x, self.y + other.y).into_point()
+        (self.x * other.x, self.y * other.y).into_point()
     }
 }
-impl<T: Num + AddAssign<T>, U: PassPoint<T>> AddAssign<U> for Point<T> {
+impl<T: Num + MulAssign<T>, U: PassPoint<T>> MulAssign<U> for Point<T> {
     #[inline]
-    fn add_assign(&mut self, rhs: U) {
+    fn mul_assign(&mut self, rhs: U) {
         let rhs = rhs.pass();
-        self.x += rhs.x;
-        self.y += rhs.y;
+        self.x *= rhs.x;
+        self.y *= rhs.y;
     }
 }
-impl<T: Num, U: PassPoint<T>> Sub<U> for Point<T> {
+impl<T: Num, U: PassPoint<T>> Div<U> for Point<T> {
     type Output = Self;
     #[inline]
-    fn sub(self, other: U) -> Point<T> {
+    fn div(self, other: U) -> Point<T> {
         let other = other.pass();
-        (self.x - other.x, self.y - other.y).into_point()
+        (self.x / other.x, self.y / other.y).into_point()
     }
 }
-/// 暂时无法为 `Point<&mut T>` 实现 `SubAssign`.
-/// 故将其放在成员函数当中。
-impl<T: Num + SubAssign<T>, U: PassPoint<T>> SubAssign<U> for Point<T> {
+impl<T: Num + DivAssign<T>, U: PassPoint<T>> DivAssign<U> for Point<T> {
     #[inline]
-    fn sub_assign(&mut self, rhs: U) {
+    fn div_assign(&mut self, rhs: U) {
         let rhs = rhs.pass();
-        self.x -= rhs.x;
-        self.y -= rhs.y;
+        self.x /= rhs.x;
+        self.y /= rhs.y;
     }
 }
+impl<T: Num, U: PassPoint<T>> Rem<U> for Point<T> {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: U) -> Point<T> {
+        let other = other.pass();
+        (self.x % other.x, self.y % other.y).into_point()
+    }
+}
+impl<T: Num + RemAssign<T>, U: PassPoint<T>> RemAssign<U> for Point<T> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: U) {
+        let rhs = rhs.pass();
+        self.x %= rhs.x;
+        self.y %= rhs.y;
+    }
+}
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Point<T> {
+        self.map(Neg::neg)
+    }
+}
+/// 标量运算无法对任意 `T` 实现（会与按分量运算的实现冲突），
+/// 故只为基本数值类型实现。
+macro_rules! impl_scalar_ops {
+    ($name:ident; $($t:ty)*) => {
+        $(
+            impl Mul<$t> for $name<$t> {
+                type Output = Self;
+                #[inline]
+                fn mul(self, rhs: $t) -> $name<$t> {
+                    self.map(|v| v * rhs)
+                }
+            }
+            impl Mul<&$t> for $name<$t> {
+                type Output = Self;
+                #[inline]
+                fn mul(self, rhs: &$t) -> $name<$t> {
+                    self * *rhs
+                }
+            }
+            impl MulAssign<$t> for $name<$t> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $t) {
+                    self.x *= rhs;
+                    self.y *= rhs;
+                }
+            }
+            impl MulAssign<&$t> for $name<$t> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: &$t) {
+                    *self *= *rhs;
+                }
+            }
+            impl Div<$t> for $name<$t> {
+                type Output = Self;
+                #[inline]
+                fn div(self, rhs: $t) -> $name<$t> {
+                    self.map(|v| v / rhs)
+                }
+            }
+            impl Div<&$t> for $name<$t> {
+                type Output = Self;
+                #[inline]
+                fn div(self, rhs: &$t) -> $name<$t> {
+                    self / *rhs
+                }
+            }
+            impl DivAssign<$t> for $name<$t> {
+                #[inline]
+                fn div_assign(&mut self, rhs: $t) {
+                    self.x /= rhs;
+                    self.y /= rhs;
+                }
+            }
+            impl DivAssign<&$t> for $name<$t> {
+                #[inline]
+                fn div_assign(&mut self, rhs: &$t) {
+                    *self /= *rhs;
+                }
+            }
+            impl Rem<$t> for $name<$t> {
+                type Output = Self;
+                #[inline]
+                fn rem(self, rhs: $t) -> $name<$t> {
+                    self.map(|v| v % rhs)
+                }
+            }
+            impl Rem<&$t> for $name<$t> {
+                type Output = Self;
+                #[inline]
+                fn rem(self, rhs: &$t) -> $name<$t> {
+                    self % *rhs
+                }
+            }
+            impl RemAssign<$t> for $name<$t> {
+                #[inline]
+                fn rem_assign(&mut self, rhs: $t) {
+                    self.x %= rhs;
+                    self.y %= rhs;
+                }
+            }
+            impl RemAssign<&$t> for $name<$t> {
+                #[inline]
+                fn rem_assign(&mut self, rhs: &$t) {
+                    *self %= *rhs;
+                }
+            }
+        )*
+    };
+}
+impl_scalar_ops!(Point; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+impl_scalar_ops!(Vector; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
 /// 两点之间的直线距离。
+#[deprecated(note = "请使用 `Euclidean.distance(&a, &b)`, 见 `Metric`")]
+#[allow(deprecated)]
 pub fn distance<T: NumCast>(a: Point<T>, b: Point<T>) -> f64 {
     distance_sq_f64(a, b).sqrt()
 }
 /// 两点之间直线距离的平方。
+#[deprecated(note = "坐标无法转换为 `f64` 时会 panic, 请使用 `Euclidean` 等 `Metric`")]
 pub fn distance_sq_f64<T: NumCast>(a: Point<T>, b: Point<T>) -> f64 {
     let p = a.map(|num| num.to_f64().unwrap());
     let q = b.map(|num| num.to_f64().unwrap());
     (p.x - q.x).powf(2.0) + (p.y - q.y).powf(2.0)
 }
 /// 两点之间直线距离的平方。
+#[deprecated(note = "整数坐标可能溢出，请使用 `SquaredEuclidean.distance(&p, &q)`, 见 `Metric`")]
 pub fn distance_sq<T: Num + Copy>(p: Point<T>, q: Point<T>) -> T {
     (p.x - q.x) * (p.x - q.x) + (p.y - q.y) * (p.y - q.y)
 }
@@ -30,6 +30,18 @@ impl_point2d!(imageproc::point::Point<T>, Struct, Struct);
 
 #[cfg(feature = "euclid")]
 impl_point2d!(euclid::Point2D<T, U>, Struct, New);
+/// 保留 `euclid` 的单位参数。
+#[cfg(feature = "euclid")]
+impl<T, U> crate::point_2d::TypedPoint2D<T, U> for euclid::Point2D<T, U> {
+    #[inline]
+    fn into_typed_point(self) -> crate::point_2d::TypedPoint<T, U> {
+        crate::point_2d::TypedPoint::new(self.x, self.y)
+    }
+    #[inline]
+    fn from_typed_point(p: crate::point_2d::TypedPoint<T, U>) -> Self {
+        euclid::Point2D::new(p.x, p.y)
+    }
+}
 
 #[cfg(feature = "core-graphics")]
 use core_graphics::base::CGFloat;
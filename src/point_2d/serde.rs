@@ -0,0 +1,110 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 配合 `#[serde(with = "...")]` 使用的辅助模块，用于选择二维点的序列化格式。
+//!
+//! 这些模块对所有实现了 [`Point2D`] 的类型都适用，包括 `glam::Vec2` 等外部类型，
+//! 序列化时会经由内建的 [`Point`] 类型转换。
+//!
+//! - [`map`] 对应 `{"x": .., "y": ..}`, 与 [`Point`] 本身的格式相同。
+//! - [`array`] 对应 `[x, y]`.
+//! - [`string`] 对应 `"x,y"`, 反序列化时也接受 [`Point`] 的 [`FromStr`](std::str::FromStr) 所支持的其他格式。
+//!
+//! [`Point`]: crate::point_2d::Point
+
+use crate::point_2d::Point2D;
+
+/// 以 `{"x": .., "y": ..}` 的格式序列化。
+pub mod map {
+    use super::Point2D;
+    use crate::point_2d::Point;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<P, T, S>(point: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: Point2D<T>,
+        T: Serialize,
+        S: Serializer,
+    {
+        point.as_point().serialize(serializer)
+    }
+    pub fn deserialize<'de, P, T, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: Point2D<T>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Point::deserialize(deserializer).map(P::from_point)
+    }
+}
+
+/// 以 `[x, y]` 的格式序列化。
+pub mod array {
+    use super::Point2D;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<P, T, S>(point: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: Point2D<T>,
+        T: Serialize,
+        S: Serializer,
+    {
+        [point.rx(), point.ry()].serialize(serializer)
+    }
+    pub fn deserialize<'de, P, T, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: Point2D<T>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        <[T; 2]>::deserialize(deserializer).map(|xy| P::from_point(xy.into_point()))
+    }
+}
+
+/// 以 `"x,y"` 的字符串格式序列化。
+pub mod string {
+    use super::Point2D;
+    use crate::point_2d::{Point, PointStyle};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<P, T, S>(point: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: Point2D<T>,
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(&point.as_point().display(PointStyle::Comma))
+    }
+    pub fn deserialize<'de, P, T, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: Point2D<T>,
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse::<Point<T>>()
+            .map(P::from_point)
+            .map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,153 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::Point;
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// 带有坐标空间（单位）标记的二维点类型应当实现该 trait.
+///
+/// 与 [`Point2D`](crate::point_2d::Point2D) 不同，通过该 trait 进行的转换会保留单位 `U`,
+/// 因此不同坐标空间的点不能相互转换，只能通过 [`TypedPoint::cast_unit`]
+/// 或 [`TypedTransform2D`](crate::transform_2d::TypedTransform2D) 显式地改变坐标空间。
+pub trait TypedPoint2D<T, U>: Sized {
+    /// 转换为内建 [`TypedPoint`] 类型。
+    fn into_typed_point(self) -> TypedPoint<T, U>;
+    /// 通过内建 [`TypedPoint`] 类型构造。
+    fn from_typed_point(p: TypedPoint<T, U>) -> Self;
+    /// 转换为同一坐标空间中的另一种点类型。
+    #[inline]
+    fn convert_typed<O: TypedPoint2D<T, U>>(self) -> O {
+        O::from_typed_point(self.into_typed_point())
+    }
+}
+
+/// # [`TypedPoint`]
+/// 带有坐标空间标记 `U` 的 [`Point`], 用作带单位的二维点类型之间转换的的桥梁。
+///
+/// `U` 仅用于在编译期区分坐标空间，如 `euclid` 中的单位类型，不占用空间，也不要求实现任何 trait.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct TypedPoint<T, U> {
+    pub x: T,
+    pub y: T,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _unit: PhantomData<U>,
+}
+
+impl<T, U> TypedPoint2D<T, U> for TypedPoint<T, U> {
+    #[inline]
+    fn into_typed_point(self) -> TypedPoint<T, U> {
+        self
+    }
+    #[inline]
+    fn from_typed_point(p: TypedPoint<T, U>) -> Self {
+        p
+    }
+}
+
+impl<T, U> TypedPoint<T, U> {
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        TypedPoint {
+            x,
+            y,
+            _unit: PhantomData,
+        }
+    }
+    /// 为无单位的点标记坐标空间。
+    #[inline]
+    pub fn from_untyped(p: Point<T>) -> Self {
+        TypedPoint::new(p.x, p.y)
+    }
+    /// 去掉坐标空间标记。
+    #[inline]
+    pub fn to_untyped(self) -> Point<T> {
+        Point {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    /// 显式地改为另一坐标空间，坐标值不变。
+    #[inline]
+    pub fn cast_unit<V>(self) -> TypedPoint<T, V> {
+        TypedPoint::new(self.x, self.y)
+    }
+    #[inline]
+    pub fn as_ref(&self) -> TypedPoint<&T, U> {
+        TypedPoint::new(&self.x, &self.y)
+    }
+    #[inline]
+    pub fn as_mut(&mut self) -> TypedPoint<&mut T, U> {
+        TypedPoint::new(&mut self.x, &mut self.y)
+    }
+    #[inline]
+    pub fn map<V, F: Fn(T) -> V>(self, f: F) -> TypedPoint<V, U> {
+        TypedPoint::new(f(self.x), f(self.y))
+    }
+    #[inline]
+    pub fn from_typed_point_2d<I: TypedPoint2D<T, U>>(p: I) -> Self {
+        p.into_typed_point()
+    }
+    #[inline]
+    pub fn into_typed_point_2d<O: TypedPoint2D<T, U>>(self) -> O {
+        O::from_typed_point(self)
+    }
+}
+
+impl<T: Copy, U> Copy for TypedPoint<T, U> {}
+impl<T: Clone, U> Clone for TypedPoint<T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        TypedPoint::new(self.x.clone(), self.y.clone())
+    }
+}
+impl<T: PartialEq, U> PartialEq for TypedPoint<T, U> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+impl<T: Eq, U> Eq for TypedPoint<T, U> {}
+impl<T: Hash, U> Hash for TypedPoint<T, U> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+impl<T: Default, U> Default for TypedPoint<T, U> {
+    #[inline]
+    fn default() -> Self {
+        TypedPoint::new(T::default(), T::default())
+    }
+}
+impl<T: Debug, U> Debug for TypedPoint<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedPoint")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .finish()
+    }
+}
@@ -0,0 +1,267 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::point_2d::{Point, Point2D, Point2DMut};
+use num_traits::{Float, Num};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// # [`Vector`]
+/// 内建 `Vector` 类型，表示二维位移，与表示位置的 [`Point`] 相区分。
+///
+/// 两点相减得到 `Vector`, 点与 `Vector` 相加减得到 [`Point`].
+///
+/// `Vector` 同样实现了 [`Point2D`], 可以借助 [`Point`] 与其他二维类型相互转换。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+impl<T> Point2D<T> for Vector<T> {
+    #[inline]
+    fn rx(&self) -> &T {
+        &self.x
+    }
+    #[inline]
+    fn ry(&self) -> &T {
+        &self.y
+    }
+    #[inline]
+    fn into_point(self) -> Point<T> {
+        Point {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn from_point(point_2d: Point<T>) -> Self {
+        Vector {
+            x: point_2d.x,
+            y: point_2d.y,
+        }
+    }
+}
+impl<T> Point2DMut<T> for Vector<T> {
+    #[inline]
+    fn as_point_mut(&mut self) -> Point<&mut T> {
+        Point {
+            x: &mut self.x,
+            y: &mut self.y,
+        }
+    }
+}
+pub trait PassVector<T> {
+    fn pass(self) -> Vector<T>;
+}
+impl<T> PassVector<T> for Vector<T> {
+    #[inline]
+    fn pass(self) -> Vector<T> {
+        self
+    }
+}
+impl<T> PassVector<T> for Vector<&T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn pass(self) -> Vector<T> {
+        Vector {
+            x: *self.x,
+            y: *self.y,
+        }
+    }
+}
+impl<T> PassVector<T> for Vector<&mut T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn pass(self) -> Vector<T> {
+        Vector {
+            x: *self.x,
+            y: *self.y,
+        }
+    }
+}
+impl<T> PassVector<T> for &Vector<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn pass(self) -> Vector<T> {
+        *self
+    }
+}
+impl<T> Vector<T> {
+    #[inline]
+    pub fn new(x: T, y: T) -> Vector<T> {
+        Vector::<T> { x, y }
+    }
+    #[inline]
+    pub const fn as_ref(&self) -> Vector<&T> {
+        Vector {
+            x: &self.x,
+            y: &self.y,
+        }
+    }
+    #[inline]
+    pub fn as_mut(&mut self) -> Vector<&mut T> {
+        Vector {
+            x: &mut self.x,
+            y: &mut self.y,
+        }
+    }
+    #[inline]
+    pub fn from_point_2d<I>(p: I) -> Self
+    where
+        I: Point2D<T>,
+    {
+        Self::from_point(p.into_point())
+    }
+    #[inline]
+    pub fn into_point_2d<O>(self) -> O
+    where
+        O: Point2D<T>,
+    {
+        O::from_point(self.into_point())
+    }
+    /// 转换为以该向量为坐标的 [`Point`], 即原点平移该向量后得到的点。
+    #[inline]
+    pub fn to_point(self) -> Point<T> {
+        self.into_point()
+    }
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Vector<U> {
+        Vector {
+            x: f(self.x),
+            y: f(self.y),
+        }
+    }
+    /// 点积。
+    #[inline]
+    pub fn dot<U: PassVector<T>>(self, other: U) -> T
+    where
+        T: Num,
+    {
+        let other = other.pass();
+        self.x * other.x + self.y * other.y
+    }
+    /// 二维叉积（perp-dot product）, 即 `self.x * other.y - self.y * other.x`.
+    ///
+    /// 结果为正表示 `other` 在 `self` 的逆时针方向。
+    #[inline]
+    pub fn cross<U: PassVector<T>>(self, other: U) -> T
+    where
+        T: Num,
+    {
+        let other = other.pass();
+        self.x * other.y - self.y * other.x
+    }
+    /// 逆时针旋转 90° 后的向量，即 `(-y, x)`.
+    #[inline]
+    pub fn perp(self) -> Vector<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Vector {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+    /// 长度的平方。
+    #[inline]
+    pub fn length_sq(self) -> T
+    where
+        T: Num + Copy,
+    {
+        self.dot(self)
+    }
+    /// 长度。
+    #[inline]
+    pub fn length(self) -> T
+    where
+        T: Float,
+    {
+        self.x.hypot(self.y)
+    }
+    /// 同方向的单位向量。长度为零或不是有限值时返回 `None`.
+    #[inline]
+    pub fn normalize(self) -> Option<Vector<T>>
+    where
+        T: Float,
+    {
+        let length = self.length();
+        if length.is_normal() {
+            Some(self.map(|v| v / length))
+        } else {
+            None
+        }
+    }
+    /// 从 `self` 转到 `other` 的有符号夹角（弧度），范围为 `[-π, π]`, 逆时针为正。
+    #[inline]
+    pub fn angle_to<U: PassVector<T>>(self, other: U) -> T
+    where
+        T: Float,
+    {
+        let other = other.pass();
+        self.cross(other).atan2(self.dot(other))
+    }
+}
+impl<T: Num, U: PassVector<T>> Add<U> for Vector<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: U) -> Vector<T> {
+        let other = other.pass();
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+impl<T: Num + AddAssign<T>, U: PassVector<T>> AddAssign<U> for Vector<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        let rhs = rhs.pass();
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+impl<T: Num, U: PassVector<T>> Sub<U> for Vector<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: U) -> Vector<T> {
+        let other = other.pass();
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+impl<T: Num + SubAssign<T>, U: PassVector<T>> SubAssign<U> for Vector<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: U) {
+        let rhs = rhs.pass();
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Vector<T> {
+        self.map(Neg::neg)
+    }
+}
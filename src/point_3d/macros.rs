@@ -0,0 +1,77 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+#[macro_export]
+macro_rules! impl_point3d {
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>,Tuple, $expr:tt$(, $xx:tt, $yy:tt, $zz:tt)?) => {
+        impl_point3d!($root$(::$idents)*<$t$(,$other)*>,0,1,2,$expr$(, $xx, $yy, $zz)?);
+    };
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>,Struct, $expr:tt$(, $xx:tt, $yy:tt, $zz:tt)?) => {
+        impl_point3d!($root$(::$idents)*<$t$(,$other)*>,x,y,z,$expr$(, $xx, $yy, $zz)?);
+    };
+    (impl $(<$t1:ident$(, $other1:ident)*>)? Trait<$t2:ident> for $root:ident $(:: $idents:ident )*$(<$t3:ident$(, $other2:ident)*>)?$(where {$($case:tt)?*})?, $x:tt, $y:tt, $z:tt, $expr:tt$(, $xx:tt, $yy:tt, $zz:tt)?) => {
+        impl$(<$t1$(,$other1)*>)? $crate::point_3d::Point3D<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)?*)?{
+            #[inline]
+            fn rx(&self) -> &$t2 {
+                &self.$x
+            }
+            #[inline]
+            fn ry(&self) -> &$t2 {
+                &self.$y
+            }
+            #[inline]
+            fn rz(&self) -> &$t2 {
+                &self.$z
+            }
+            #[inline]
+            fn into_point(self) -> $crate::point_3d::Point3<$t2> {
+                $crate::point_3d::Point3 {
+                    x: self.$x,
+                    y: self.$y,
+                    z: self.$z,
+                }
+            }
+            #[inline]
+            fn from_point(p: $crate::point_3d::Point3<$t2>) -> Self {
+                let xyz = impl_point3d!($expr, $t2$(, $xx, $yy, $zz)?, $x, $y, $z);
+                xyz(p)
+            }
+        }
+    };
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>, $x:tt, $y:tt, $z:tt, $expr:tt$(, $xx:tt, $yy:tt, $zz:tt)?) => {
+        impl_point3d!(impl<$t$(, $other)*> Trait<$t> for $root $(:: $idents )*<$t$(, $other)*>, $x, $y, $z, $expr$(, $xx, $yy, $zz)?);
+    };
+    (New, $t:ident$(, $xx:tt, $yy:tt, $zz:tt)?)=>{
+        |p: $crate::point_3d::Point3<$t>| -> Self{
+            Self::new(p.x, p.y, p.z)
+        }
+    };
+    (Tuple, $t:ident, $xx:tt, $yy:tt, $zz:tt$(, $x:tt, $y:tt, $z:tt)?)=>{
+        |p: $crate::point_3d::Point3<$t>| -> Self{
+            Self(p.x, p.y, p.z)
+        }
+    };
+    (Struct, $t:ident, $xx:tt, $yy:tt, $zz:tt$(, $x:tt, $y:tt, $z:tt)?)=>{
+        |p: $crate::point_3d::Point3<$t>| -> Self{
+            Self{$xx:p.x,$yy: p.y,$zz: p.z}
+        }
+    }
+}
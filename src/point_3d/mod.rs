@@ -0,0 +1,624 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod macros;
+pub mod points;
+
+use crate::point_2d::Point;
+use num_traits::Num;
+use std::{
+    array,
+    iter::FusedIterator,
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
+};
+
+/// 所有类似的三维点类型都应当实现该 trait.
+pub trait Point3D<T> {
+    /// 获取 x 坐标的引用。
+    fn rx(&self) -> &T;
+    /// 获取 y 坐标的引用。
+    fn ry(&self) -> &T;
+    /// 获取 z 坐标的引用。
+    fn rz(&self) -> &T;
+    /// 转换为内建 [`Point3`] 类型。
+    fn into_point(self) -> Point3<T>;
+    /// 通过内建 [`Point3`] 类型构造。
+    fn from_point(point_3d: Point3<T>) -> Self;
+    /// 使用 x, y, z 坐标的引用构造内建 [`Point3`] 类型。
+    #[inline]
+    fn as_point(&self) -> Point3<&T> {
+        Point3 {
+            x: self.rx(),
+            y: self.ry(),
+            z: self.rz(),
+        }
+    }
+}
+
+impl<T> Point3D<T> for (T, T, T) {
+    #[inline]
+    fn rx(&self) -> &T {
+        &self.0
+    }
+    #[inline]
+    fn ry(&self) -> &T {
+        &self.1
+    }
+    #[inline]
+    fn rz(&self) -> &T {
+        &self.2
+    }
+    #[inline]
+    fn into_point(self) -> Point3<T> {
+        Point3 {
+            x: self.0,
+            y: self.1,
+            z: self.2,
+        }
+    }
+    #[inline]
+    fn from_point(point_3d: Point3<T>) -> Self {
+        (point_3d.x, point_3d.y, point_3d.z)
+    }
+}
+impl<T> Point3D<T> for [T; 3] {
+    #[inline]
+    fn rx(&self) -> &T {
+        &self[0]
+    }
+    #[inline]
+    fn ry(&self) -> &T {
+        &self[1]
+    }
+    #[inline]
+    fn rz(&self) -> &T {
+        &self[2]
+    }
+    #[inline]
+    fn into_point(self) -> Point3<T> {
+        let [x, y, z] = self;
+        Point3 { x, y, z }
+    }
+    #[inline]
+    fn from_point(point_3d: Point3<T>) -> Self {
+        [point_3d.x, point_3d.y, point_3d.z]
+    }
+}
+/// # [`Point3`]
+/// 内建 `Point3` 类型，用作三维点类型之间转换的的桥梁。
+///
+/// 用法与 [`Point`] 相同：先调用 [`Point3D::into_point`] 将一个点类型转换为 `Point3`，
+/// 再调用 [`Point3::into_point_3d`] 将 `Point3` 转换为另一个点类型。
+///
+/// 可以通过 [`Point3::truncate`] 丢弃 z 坐标得到 [`Point`],
+/// 也可以通过 [`Point::extend`] 补充 z 坐标得到 `Point3`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+impl<T> Point3D<T> for Point3<T> {
+    #[inline]
+    fn rx(&self) -> &T {
+        &self.x
+    }
+    #[inline]
+    fn ry(&self) -> &T {
+        &self.y
+    }
+    #[inline]
+    fn rz(&self) -> &T {
+        &self.z
+    }
+    #[inline]
+    fn into_point(self) -> Point3<T> {
+        self
+    }
+    #[inline]
+    fn from_point(point_3d: Point3<T>) -> Self {
+        point_3d
+    }
+}
+
+/// 复制出坐标值，得到 `Point3<T>`. 为 `Point3<T>`, `&Point3<T>`, `Point3<&T>` 与 `Point3<&mut T>` 实现。
+pub trait CopiedPoint3<T>
+where
+    T: Copy,
+{
+    fn copied(&self) -> Point3<T>;
+}
+impl<T> CopiedPoint3<T> for Point3<&T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn copied(&self) -> Point3<T> {
+        Point3 {
+            x: *self.x,
+            y: *self.y,
+            z: *self.z,
+        }
+    }
+}
+impl<T> CopiedPoint3<T> for Point3<&mut T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn copied(&self) -> Point3<T> {
+        self.as_immut().copied()
+    }
+}
+impl<T> CopiedPoint3<T> for &Point3<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn copied(&self) -> Point3<T> {
+        **self
+    }
+}
+impl<T> CopiedPoint3<T> for Point3<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn copied(&self) -> Point3<T> {
+        *self
+    }
+}
+/// 可以按值传入 `Point3<T>` 的类型，用作运算符与 `add_assign` 等函数的参数，
+/// 以便同时接受 `Point3<T>`, `&Point3<T>`, `Point3<&T>` 与 `Point3<&mut T>`.
+pub trait PassPoint3<T> {
+    fn pass(self) -> Point3<T>;
+}
+impl<T> PassPoint3<T> for Point3<T> {
+    #[inline]
+    fn pass(self) -> Point3<T> {
+        self
+    }
+}
+impl<T> PassPoint3<T> for Point3<&T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn pass(self) -> Point3<T> {
+        self.copied()
+    }
+}
+impl<T> PassPoint3<T> for Point3<&mut T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn pass(self) -> Point3<T> {
+        self.copied()
+    }
+}
+impl<T> PassPoint3<T> for &Point3<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn pass(self) -> Point3<T> {
+        self.copied()
+    }
+}
+/// 克隆出坐标值，得到 `Point3<T>`. 与 [`CopiedPoint3`] 相同，但只要求 `T: Clone`.
+pub trait ClonedPoint3<T>
+where
+    T: Clone,
+{
+    fn cloned(&self) -> Point3<T>;
+}
+impl<T> ClonedPoint3<T> for Point3<&T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn cloned(&self) -> Point3<T> {
+        Point3 {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+}
+impl<T> ClonedPoint3<T> for Point3<&mut T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn cloned(&self) -> Point3<T> {
+        self.as_immut().cloned()
+    }
+}
+impl<T> ClonedPoint3<T> for &Point3<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn cloned(&self) -> Point3<T> {
+        (*self).clone()
+    }
+}
+impl<T> ClonedPoint3<T> for Point3<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn cloned(&self) -> Point3<T> {
+        (*self).clone()
+    }
+}
+impl<T> Point3<&mut T> {
+    #[inline]
+    pub fn as_immut(&self) -> Point3<&T> {
+        Point3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    pub fn sub_assign<U: PassPoint3<T>>(&mut self, rhs: U)
+    where
+        T: SubAssign,
+    {
+        let rhs = rhs.pass();
+        *self.x -= rhs.x;
+        *self.y -= rhs.y;
+        *self.z -= rhs.z;
+    }
+    #[inline]
+    pub fn add_assign<U: PassPoint3<T>>(&mut self, rhs: U)
+    where
+        T: AddAssign,
+    {
+        let rhs = rhs.pass();
+        *self.x += rhs.x;
+        *self.y += rhs.y;
+        *self.z += rhs.z;
+    }
+}
+impl<T> Point3<Option<T>> {
+    #[inline]
+    pub fn unwrap(self) -> Option<Point3<T>> {
+        match self {
+            Point3 {
+                x: Some(x),
+                y: Some(y),
+                z: Some(z),
+            } => Some(Point3 { x, y, z }),
+            _ => None,
+        }
+    }
+}
+impl<T, E> Point3<Result<T, E>> {
+    #[inline]
+    pub fn unwrap(self) -> Option<Point3<T>> {
+        match self {
+            Point3 {
+                x: Ok(x),
+                y: Ok(y),
+                z: Ok(z),
+            } => Some(Point3 { x, y, z }),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn unwrap_err(self) -> Option<Point3<E>> {
+        match self {
+            Point3 {
+                x: Err(x),
+                y: Err(y),
+                z: Err(z),
+            } => Some(Point3 { x, y, z }),
+            _ => None,
+        }
+    }
+}
+impl<T, U> Point3<(T, U)> {
+    #[inline]
+    pub fn unzip(self) -> (Point3<T>, Point3<U>) {
+        (
+            Point3 {
+                x: self.x.0,
+                y: self.y.0,
+                z: self.z.0,
+            },
+            Point3 {
+                x: self.x.1,
+                y: self.y.1,
+                z: self.z.1,
+            },
+        )
+    }
+}
+impl<T> Point3<T> {
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3::<T> { x, y, z }
+    }
+    #[inline]
+    pub const fn as_ref(&self) -> Point3<&T> {
+        Point3 {
+            x: &self.x,
+            y: &self.y,
+            z: &self.z,
+        }
+    }
+    #[inline]
+    pub fn zip<U>(self, other: Point3<U>) -> Point3<(T, U)> {
+        Point3 {
+            x: (self.x, other.x),
+            y: (self.y, other.y),
+            z: (self.z, other.z),
+        }
+    }
+    #[inline]
+    pub fn as_mut(&mut self) -> Point3<&mut T> {
+        Point3 {
+            x: &mut self.x,
+            y: &mut self.y,
+            z: &mut self.z,
+        }
+    }
+    #[inline]
+    pub fn as_deref(&self) -> Point3<&<T as Deref>::Target>
+    where
+        T: Deref,
+    {
+        Point3 {
+            x: &self.x,
+            y: &self.y,
+            z: &self.z,
+        }
+    }
+    #[inline]
+    pub fn as_deref_mut(&mut self) -> Point3<&mut T::Target>
+    where
+        T: DerefMut,
+    {
+        Point3 {
+            x: &mut self.x,
+            y: &mut self.y,
+            z: &mut self.z,
+        }
+    }
+    #[inline]
+    pub fn from_point_3d<I>(p: I) -> Self
+    where
+        I: Point3D<T>,
+    {
+        p.into_point()
+    }
+    #[inline]
+    pub fn into_point_3d<O>(self) -> O
+    where
+        O: Point3D<T>,
+    {
+        O::from_point(self)
+    }
+    #[inline]
+    pub fn as_point_3d<'a, O>(&'a self) -> O
+    where
+        O: Point3D<&'a T>,
+    {
+        O::from_point(self.as_ref())
+    }
+    /// 丢弃 z 坐标，得到二维的 [`Point`].
+    #[inline]
+    pub fn truncate(self) -> Point<T> {
+        Point {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Point3<U> {
+        Point3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let Point3 { x, y, z } = self;
+        Iter {
+            inner: [x, y, z].into_iter(),
+        }
+    }
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let Point3 { x, y, z } = self;
+        IterMut {
+            inner: [x, y, z].into_iter(),
+        }
+    }
+}
+impl<T> Point<T> {
+    /// 补充 z 坐标，得到三维的 [`Point3`].
+    #[inline]
+    pub fn extend(self, z: T) -> Point3<T> {
+        Point3 {
+            x: self.x,
+            y: self.y,
+            z,
+        }
+    }
+}
+#[derive(Debug)]
+pub struct Iter<'a, A: 'a> {
+    inner: array::IntoIter<&'a A, 3>,
+}
+impl<'a, A> Iterator for Iter<'a, A> {
+    type Item = &'a A;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, A> DoubleEndedIterator for Iter<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a A> {
+        self.inner.next_back()
+    }
+}
+
+impl<A> ExactSizeIterator for Iter<'_, A> {}
+
+impl<A> FusedIterator for Iter<'_, A> {}
+
+impl<A> Clone for Iter<'_, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            inner: self.inner.clone(),
+        }
+    }
+}
+#[derive(Debug)]
+pub struct IterMut<'a, A: 'a> {
+    inner: array::IntoIter<&'a mut A, 3>,
+}
+
+impl<'a, A> Iterator for IterMut<'a, A> {
+    type Item = &'a mut A;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut A> {
+        self.inner.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, A> DoubleEndedIterator for IterMut<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut A> {
+        self.inner.next_back()
+    }
+}
+
+impl<A> ExactSizeIterator for IterMut<'_, A> {}
+
+impl<A> FusedIterator for IterMut<'_, A> {}
+#[derive(Clone, Debug)]
+pub struct IntoIter<A> {
+    inner: array::IntoIter<A, 3>,
+}
+impl<A> Iterator for IntoIter<A> {
+    type Item = A;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<T> IntoIterator for Point3<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.into_point_3d::<[T; 3]>().into_iter(),
+        }
+    }
+}
+impl<'a, T> IntoIterator for &'a Point3<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a mut Point3<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<A> DoubleEndedIterator for IntoIter<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        self.inner.next_back()
+    }
+}
+
+impl<A> ExactSizeIterator for IntoIter<A> {}
+
+impl<A> FusedIterator for IntoIter<A> {}
+
+impl<T: Num, U: PassPoint3<T>> Add<U> for Point3<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: U) -> Point3<T> {
+        let other = other.pass();
+        (self.x + other.x, self.y + other.y, self.z + other.z).into_point()
+    }
+}
+impl<T: Num + AddAssign<T>, U: PassPoint3<T>> AddAssign<U> for Point3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        let rhs = rhs.pass();
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+impl<T: Num, U: PassPoint3<T>> Sub<U> for Point3<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: U) -> Point3<T> {
+        let other = other.pass();
+        (self.x - other.x, self.y - other.y, self.z - other.z).into_point()
+    }
+}
+impl<T: Num + SubAssign<T>, U: PassPoint3<T>> SubAssign<U> for Point3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: U) {
+        let rhs = rhs.pass();
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
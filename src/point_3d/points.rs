@@ -0,0 +1,42 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+#[allow(unused_imports)]
+use crate::impl_point3d;
+
+#[cfg(feature = "euclid")]
+impl_point3d!(euclid::Point3D<T, U>, Struct, New);
+
+#[cfg(feature = "glam")]
+pub mod glam_points {
+    use crate::impl_point3d;
+    impl_point3d!(impl Trait<bool> for glam::BVec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<f32> for glam::Vec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<f64> for glam::DVec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<i8> for glam::I8Vec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<i16> for glam::I16Vec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<i32> for glam::IVec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<i64> for glam::I64Vec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<u8> for glam::U8Vec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<u16> for glam::U16Vec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<u32> for glam::UVec3, x, y, z, Struct);
+    impl_point3d!(impl Trait<u64> for glam::U64Vec3, x, y, z, Struct);
+}
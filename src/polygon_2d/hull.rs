@@ -0,0 +1,417 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    point_2d::{get, Point, Point2D},
+    polygon_2d::Polygon,
+    predicates::ExactPredicates,
+};
+use std::cmp::Ordering;
+
+/// 凸包边上（非顶点处）的共线点的处理方式。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Collinear {
+    /// 保留，凸包包含所有在边界上的点。
+    Keep,
+    /// 丢弃，凸包只包含顶点。
+    #[default]
+    Drop,
+}
+
+impl Collinear {
+    /// 扫描时遇到方向为 `o` 的转向是否应当弹出栈顶。
+    #[inline]
+    fn pops(self, o: Ordering) -> bool {
+        match self {
+            Collinear::Keep => o == Ordering::Less,
+            Collinear::Drop => o != Ordering::Greater,
+        }
+    }
+}
+
+#[inline]
+fn cmp_xy<T: PartialOrd>(a: &Point<T>, b: &Point<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+#[inline]
+fn cmp_yx<T: PartialOrd>(a: &Point<T>, b: &Point<T>) -> Ordering {
+    a.y.partial_cmp(&b.y)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal))
+}
+
+/// 复制坐标，并去掉含 `NaN` 的点与重复的点（保留下标最小的一个），按 `(x, y)` 排序。
+fn prepare<P, T>(points: &[P]) -> (Vec<Point<T>>, Vec<usize>)
+where
+    P: Point2D<T>,
+    T: Copy + PartialOrd,
+{
+    let pts: Vec<Point<T>> = points.iter().map(get).collect();
+    let mut idx: Vec<usize> = (0..pts.len())
+        .filter(|&i| pts[i].x.partial_cmp(&pts[i].x).is_some())
+        .filter(|&i| pts[i].y.partial_cmp(&pts[i].y).is_some())
+        .collect();
+    idx.sort_by(|&a, &b| cmp_xy(&pts[a], &pts[b]));
+    idx.dedup_by(|a, b| pts[*a] == pts[*b]);
+    (pts, idx)
+}
+
+/// 已排序的点是否全部共线。
+fn all_collinear<T: ExactPredicates>(pts: &[Point<T>], sorted: &[usize]) -> bool {
+    let (first, last) = (pts[sorted[0]], pts[sorted[sorted.len() - 1]]);
+    sorted
+        .iter()
+        .all(|&i| T::orient2d(first, last, pts[i]) == Ordering::Equal)
+}
+
+/// 全部共线时的凸包：保留共线点时为按坐标排序的所有点，否则为两端点。
+fn degenerate_hull(sorted: Vec<usize>, collinear: Collinear) -> Vec<usize> {
+    match collinear {
+        Collinear::Keep => sorted,
+        Collinear::Drop => vec![sorted[0], sorted[sorted.len() - 1]],
+    }
+}
+
+/// 以 Andrew 单调链算法计算凸包，返回凸包顶点在 `points` 中的下标。
+///
+/// 顶点按逆时针排列（y 轴向上），从 x 最小（其次 y 最小）的点开始。重复的点只保留下标最小的一个，
+/// 含 `NaN` 的点被忽略。所有点共线时，凸包退化为线段。时间复杂度为 O(n log n).
+pub fn convex_hull_indices<P, T>(points: &[P], collinear: Collinear) -> Vec<usize>
+where
+    P: Point2D<T>,
+    T: ExactPredicates,
+{
+    let (pts, sorted) = prepare(points);
+    if sorted.len() <= 2 {
+        return sorted;
+    }
+    if all_collinear(&pts, &sorted) {
+        return degenerate_hull(sorted, collinear);
+    }
+    let mut hull: Vec<usize> = Vec::with_capacity(sorted.len() + 1);
+    // 下凸壳从左到右，上凸壳从右到左。
+    for pass in [
+        &sorted[..],
+        &sorted.iter().rev().copied().collect::<Vec<_>>()[..],
+    ] {
+        let start = hull.len();
+        for &i in pass {
+            while hull.len() >= start + 2
+                && collinear.pops(T::orient2d(
+                    pts[hull[hull.len() - 2]],
+                    pts[hull[hull.len() - 1]],
+                    pts[i],
+                ))
+            {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        // 每条链的最后一个点是另一条链的第一个点。
+        hull.pop();
+    }
+    hull
+}
+
+/// 以 Andrew 单调链算法计算凸包，见 [`convex_hull_indices`].
+#[inline]
+pub fn convex_hull<P, T>(points: &[P], collinear: Collinear) -> Polygon<P>
+where
+    P: Point2D<T> + Clone,
+    T: ExactPredicates,
+{
+    convex_hull_indices(points, collinear)
+        .into_iter()
+        .map(|i| points[i].clone())
+        .collect()
+}
+
+/// 以 Graham 扫描法计算凸包，返回凸包顶点在 `points` 中的下标。
+///
+/// 顶点按逆时针排列（y 轴向上），从 y 最小（其次 x 最小）的点开始。其余约定与 [`convex_hull_indices`] 相同。
+pub fn graham_scan_indices<P, T>(points: &[P], collinear: Collinear) -> Vec<usize>
+where
+    P: Point2D<T>,
+    T: ExactPredicates,
+{
+    let (pts, sorted) = prepare(points);
+    if sorted.len() <= 2 {
+        return sorted;
+    }
+    if all_collinear(&pts, &sorted) {
+        return degenerate_hull(sorted, collinear);
+    }
+    let pivot = sorted
+        .iter()
+        .copied()
+        .min_by(|&a, &b| cmp_yx(&pts[a], &pts[b]))
+        .unwrap_or(sorted[0]);
+    let o = pts[pivot];
+    let mut rest: Vec<usize> = sorted.into_iter().filter(|&i| i != pivot).collect();
+    // 其余点都在基准点的上方或正右方，故可以按极角排序；极角相同时近者在前。
+    let by_angle = |a: &usize, b: &usize| {
+        let (a, b) = (pts[*a], pts[*b]);
+        T::orient2d(o, b, a).then_with(|| cmp_yx(&a, &b))
+    };
+    rest.sort_by(by_angle);
+    if collinear == Collinear::Keep {
+        // 最后一条边回到基准点，其上的共线点应当由远及近。
+        let last = pts[rest[rest.len() - 1]];
+        let run = rest
+            .iter()
+            .rev()
+            .take_while(|&&i| T::orient2d(o, last, pts[i]) == Ordering::Equal)
+            .count();
+        let len = rest.len();
+        rest[len - run..].reverse();
+    }
+    let mut hull = vec![pivot];
+    for i in rest {
+        while hull.len() >= 2
+            && collinear.pops(T::orient2d(
+                pts[hull[hull.len() - 2]],
+                pts[hull[hull.len() - 1]],
+                pts[i],
+            ))
+        {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    hull
+}
+
+/// 以 Graham 扫描法计算凸包，见 [`graham_scan_indices`].
+#[inline]
+pub fn graham_scan<P, T>(points: &[P], collinear: Collinear) -> Polygon<P>
+where
+    P: Point2D<T> + Clone,
+    T: ExactPredicates,
+{
+    graham_scan_indices(points, collinear)
+        .into_iter()
+        .map(|i| points[i].clone())
+        .collect()
+}
+
+/// # [`IncrementalHull`]
+/// 逐点插入的凸包，适用于点陆续到达的情况。
+///
+/// 落在当前凸包内部的点被直接丢弃，否则以当前凸包顶点与新点重新计算凸包。
+/// 由于内部的点不会再成为凸包顶点，只需保存凸包本身。
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct IncrementalHull<P> {
+    hull: Vec<P>,
+    collinear: Collinear,
+}
+
+impl<P> IncrementalHull<P> {
+    #[inline]
+    pub const fn new(collinear: Collinear) -> Self {
+        IncrementalHull {
+            hull: Vec::new(),
+            collinear,
+        }
+    }
+    /// 当前凸包的顶点，顺序与 [`convex_hull_indices`] 相同。
+    #[inline]
+    pub fn hull(&self) -> &[P] {
+        &self.hull
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.hull.len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hull.is_empty()
+    }
+    #[inline]
+    pub fn into_polygon(self) -> Polygon<P> {
+        Polygon::new(self.hull)
+    }
+    /// 插入一个点，返回凸包是否改变。
+    pub fn insert<T>(&mut self, p: P) -> bool
+    where
+        P: Point2D<T> + Clone,
+        T: ExactPredicates,
+    {
+        if self.hull.len() >= 3 && self.absorbs(&get(&p)) {
+            return false;
+        }
+        let old_len = self.hull.len();
+        self.hull.push(p);
+        let indices = convex_hull_indices(&self.hull, self.collinear);
+        let changed = indices.len() != old_len || indices.contains(&old_len);
+        self.hull = indices.into_iter().map(|i| self.hull[i].clone()).collect();
+        changed
+    }
+    /// 新点是否不会改变凸包：在内部，或不保留共线点时在边界上。
+    fn absorbs<T>(&self, p: &Point<T>) -> bool
+    where
+        P: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let n = self.hull.len();
+        let mut on_boundary = false;
+        for i in 0..n {
+            let (a, b) = (get(&self.hull[i]), get(&self.hull[(i + 1) % n]));
+            match T::orient2d(a, b, *p) {
+                Ordering::Less => return false,
+                Ordering::Equal => on_boundary = true,
+                Ordering::Greater => {}
+            }
+        }
+        !on_boundary || self.collinear == Collinear::Drop || self.hull.iter().any(|q| get(q) == *p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const MODES: [Collinear; 2] = [Collinear::Keep, Collinear::Drop];
+
+    /// 检查凸包顶点按逆时针排列：`Drop` 时每处都严格左转，`Keep` 时允许共线。
+    fn assert_ccw(points: &[(i32, i32)], hull: &[usize], collinear: Collinear) {
+        let n = hull.len();
+        if n < 3 {
+            return;
+        }
+        let p = |i: usize| Point::new(points[hull[i % n]].0, points[hull[i % n]].1);
+        let mut turned = false;
+        for i in 0..n {
+            let o = i32::orient2d(p(i), p(i + 1), p(i + 2));
+            turned |= o == Ordering::Greater;
+            match collinear {
+                Collinear::Keep => assert_ne!(o, Ordering::Less, "{hull:?}"),
+                Collinear::Drop => assert_eq!(o, Ordering::Greater, "{hull:?}"),
+            }
+        }
+        let polygon: Polygon<_> = hull.iter().map(|&i| points[i]).collect();
+        // 保留共线点时，所有点共线的凸包面积为零。
+        assert_eq!(polygon.twice_signed_area() > 0, turned);
+    }
+
+    fn incremental(points: &[(i32, i32)], collinear: Collinear) -> Vec<(i32, i32)> {
+        let mut hull = IncrementalHull::new(collinear);
+        for &p in points {
+            hull.insert(p);
+        }
+        hull.hull().to_vec()
+    }
+
+    #[test]
+    fn empty_and_single_point() {
+        let empty: [(i32, i32); 0] = [];
+        for collinear in MODES {
+            assert!(convex_hull_indices(&empty, collinear).is_empty());
+            assert!(graham_scan_indices(&empty, collinear).is_empty());
+            assert!(incremental(&empty, collinear).is_empty());
+            assert_eq!(convex_hull_indices(&[(3, 4)], collinear), [0]);
+            assert_eq!(graham_scan_indices(&[(3, 4)], collinear), [0]);
+            assert_eq!(incremental(&[(3, 4)], collinear), [(3, 4)]);
+            // 重复的点只保留下标最小的一个。
+            let same = [(3, 4); 5];
+            assert_eq!(convex_hull_indices(&same, collinear), [0]);
+            assert_eq!(graham_scan_indices(&same, collinear), [0]);
+            assert_eq!(incremental(&same, collinear), [(3, 4)]);
+        }
+    }
+
+    #[test]
+    fn all_collinear() {
+        let points = [(0, 0), (2, 2), (1, 1), (3, 3), (1, 1)];
+        for f in [convex_hull_indices::<_, i32>, graham_scan_indices::<_, i32>] {
+            assert_eq!(f(&points, Collinear::Drop), [0, 3]);
+            assert_eq!(f(&points, Collinear::Keep), [0, 2, 1, 3]);
+        }
+        let set = |v: Vec<(i32, i32)>| v.into_iter().collect::<BTreeSet<_>>();
+        assert_eq!(
+            set(incremental(&points, Collinear::Drop)),
+            set(vec![(0, 0), (3, 3)])
+        );
+        assert_eq!(
+            set(incremental(&points, Collinear::Keep)),
+            set(vec![(0, 0), (1, 1), (2, 2), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn duplicates_and_boundary_points() {
+        let points = [
+            (0, 0),
+            (2, 0),
+            (2, 2),
+            (0, 2),
+            (1, 1),
+            (2, 0),
+            (1, 0),
+            (0, 0),
+        ];
+        for f in [convex_hull_indices::<_, i32>, graham_scan_indices::<_, i32>] {
+            let drop = f(&points, Collinear::Drop);
+            assert_eq!(drop, [0, 1, 2, 3]);
+            assert_ccw(&points, &drop, Collinear::Drop);
+            let keep = f(&points, Collinear::Keep);
+            assert_eq!(keep, [0, 6, 1, 2, 3]);
+            assert_ccw(&points, &keep, Collinear::Keep);
+        }
+        let drop = convex_hull(&points, Collinear::Drop);
+        assert_eq!(drop.as_slice(), [(0, 0), (2, 0), (2, 2), (0, 2)]);
+        let hull = incremental(&points, Collinear::Keep);
+        assert_eq!(hull.len(), 5);
+        assert!(Polygon::new(hull).is_convex());
+    }
+
+    #[test]
+    fn algorithms_agree_on_random_inputs() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = |m: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % m) as i32
+        };
+        for round in 0..2000 {
+            let m = [4, 10, 1000][round % 3];
+            let n = next(20) as usize;
+            let points: Vec<(i32, i32)> = (0..n).map(|_| (next(m), next(m))).collect();
+            for collinear in MODES {
+                let monotone = convex_hull_indices(&points, collinear);
+                let graham = graham_scan_indices(&points, collinear);
+                assert_ccw(&points, &monotone, collinear);
+                assert_ccw(&points, &graham, collinear);
+                let set = |v: &[usize]| v.iter().map(|&i| points[i]).collect::<BTreeSet<_>>();
+                assert_eq!(set(&monotone), set(&graham), "{points:?}");
+                let inc = incremental(&points, collinear);
+                assert_ccw(&inc, &(0..inc.len()).collect::<Vec<_>>(), collinear);
+                assert_eq!(set(&monotone), inc.into_iter().collect(), "{points:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod hull;
+mod polygon;
+mod polyline;
+
+pub use hull::*;
+pub use polygon::*;
+pub use polyline::*;
+
+/// 多边形顶点的环绕方向，以 y 轴向上的坐标系为准。
+///
+/// 在 y 轴向下的屏幕坐标系中，看上去的方向与之相反。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Winding {
+    /// 逆时针，有向面积为正。
+    CounterClockwise,
+    /// 顺时针，有向面积为负。
+    Clockwise,
+}
+
+impl Winding {
+    /// 相反的方向。
+    #[inline]
+    pub fn reverse(self) -> Self {
+        match self {
+            Winding::CounterClockwise => Winding::Clockwise,
+            Winding::Clockwise => Winding::CounterClockwise,
+        }
+    }
+}
+
+/// 判断点是否在多边形内部的规则。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum FillRule {
+    /// 从该点出发的射线与边界相交奇数次时在内部。
+    #[default]
+    EvenOdd,
+    /// 环绕数不为零时在内部。
+    NonZero,
+}
+
+/// 为以 `Vec<P>` 存储顶点的容器类型实现通用的构造与访问方法。
+macro_rules! impl_point_container {
+    ($name:ident) => {
+        impl<P> $name<P> {
+            #[inline]
+            pub const fn new(points: Vec<P>) -> Self {
+                $name { points }
+            }
+            /// 取出顶点。
+            #[inline]
+            pub fn into_inner(self) -> Vec<P> {
+                self.points
+            }
+            #[inline]
+            pub fn as_slice(&self) -> &[P] {
+                &self.points
+            }
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.points.len()
+            }
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.points.is_empty()
+            }
+            #[inline]
+            pub fn iter(&self) -> std::slice::Iter<'_, P> {
+                self.points.iter()
+            }
+            #[inline]
+            pub fn push(&mut self, p: P) {
+                self.points.push(p)
+            }
+            /// 反转顶点顺序。
+            #[inline]
+            pub fn reverse(&mut self) {
+                self.points.reverse()
+            }
+        }
+        impl<P> From<Vec<P>> for $name<P> {
+            #[inline]
+            fn from(points: Vec<P>) -> Self {
+                $name { points }
+            }
+        }
+        impl<P> From<$name<P>> for Vec<P> {
+            #[inline]
+            fn from(c: $name<P>) -> Self {
+                c.points
+            }
+        }
+        impl<P> FromIterator<P> for $name<P> {
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+                $name {
+                    points: iter.into_iter().collect(),
+                }
+            }
+        }
+        impl<P> Extend<P> for $name<P> {
+            #[inline]
+            fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+                self.points.extend(iter)
+            }
+        }
+        impl<P> IntoIterator for $name<P> {
+            type Item = P;
+            type IntoIter = std::vec::IntoIter<P>;
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.points.into_iter()
+            }
+        }
+        impl<'a, P> IntoIterator for &'a $name<P> {
+            type Item = &'a P;
+            type IntoIter = std::slice::Iter<'a, P>;
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.points.iter()
+            }
+        }
+    };
+}
+use impl_point_container;
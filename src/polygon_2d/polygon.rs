@@ -0,0 +1,379 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    line_2d::segments_intersect,
+    point_2d::{get, Point, Point2D, Widen},
+    polygon_2d::{folds_back, impl_point_container, FillRule, Polyline, Winding},
+    predicates::ExactPredicates,
+};
+use num_traits::{Float, Zero};
+use std::cmp::Ordering;
+
+/// # [`Polygon`]
+/// 多边形，依次连接各顶点，且最后一个顶点与第一个顶点相连。
+///
+/// 首尾顶点不必重复；重复时（常见的序列化形式）多出的一条边长度为零，[`Polygon::is_convex`]
+/// 与 [`Polygon::is_simple`] 会忽略最后一个顶点。
+///
+/// 顶点可以是任意实现了 [`Point2D`] 的类型，如 `Vec<glam::Vec2>` 可以直接包装，无需复制。
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Polygon<P> {
+    pub points: Vec<P>,
+}
+impl_point_container!(Polygon);
+
+impl<P> Polygon<P> {
+    /// 各条边的两个端点，包括从最后一个顶点回到第一个顶点的边。
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = (&P, &P)> {
+        let n = self.points.len();
+        (0..n).map(move |i| (&self.points[i], &self.points[(i + 1) % n]))
+    }
+    /// 断开首尾，转换为折线。折线的首尾顶点不相连。
+    #[inline]
+    pub fn into_polyline(self) -> Polyline<P> {
+        Polyline::new(self.points)
+    }
+    /// 两倍的有向面积：逆时针为正，顺时针为负。以鞋带公式计算。
+    ///
+    /// 整数坐标在加宽后的类型中计算，结果是精确的，见 [`Widen`].
+    pub fn twice_signed_area<T>(&self) -> T::Wide
+    where
+        P: Point2D<T>,
+        T: Widen,
+    {
+        self.edges()
+            .map(|(a, b)| T::wide_cross(get(a), get(b)))
+            .fold(Zero::zero(), T::wide_add)
+    }
+    /// 有向面积：逆时针为正，顺时针为负。以鞋带公式计算。
+    ///
+    /// 整数坐标的面积可能不是整数，请使用 [`Polygon::twice_signed_area`].
+    pub fn signed_area<T>(&self) -> T
+    where
+        P: Point2D<T>,
+        T: Float,
+    {
+        let two = T::one() + T::one();
+        self.edges()
+            .map(|(a, b)| {
+                let (a, b) = (get(a), get(b));
+                a.x * b.y - a.y * b.x
+            })
+            .fold(T::zero(), |acc, c| acc + c)
+            / two
+    }
+    /// 面积，即有向面积的绝对值。整数坐标请使用 [`Polygon::twice_signed_area`].
+    #[inline]
+    pub fn area<T>(&self) -> T
+    where
+        P: Point2D<T>,
+        T: Float,
+    {
+        self.signed_area().abs()
+    }
+    /// 周长。
+    pub fn perimeter<T>(&self) -> T
+    where
+        P: Point2D<T>,
+        T: Float,
+    {
+        self.edges()
+            .map(|(a, b)| (get(b) - get(a)).length())
+            .fold(T::zero(), |acc, l| acc + l)
+    }
+    /// 重心（面积意义上的质心）。面积为零时返回 `None`.
+    pub fn centroid<T>(&self) -> Option<Point<T>>
+    where
+        P: Point2D<T>,
+        T: Float,
+    {
+        let origin = get(self.points.first()?);
+        let (mut a2, mut cx, mut cy) = (T::zero(), T::zero(), T::zero());
+        for (a, b) in self.edges() {
+            // 以第一个顶点为原点计算，减少大坐标下的舍入误差。
+            let a = get(a) - origin;
+            let b = get(b) - origin;
+            let cross = a.cross(b);
+            a2 = a2 + cross;
+            cx = cx + (a.x + b.x) * cross;
+            cy = cy + (a.y + b.y) * cross;
+        }
+        if a2.is_zero() {
+            return None;
+        }
+        let three = T::one() + T::one() + T::one();
+        Some(Point {
+            x: origin.x + cx / (three * a2),
+            y: origin.y + cy / (three * a2),
+        })
+    }
+    /// 环绕方向，结果是精确的。
+    ///
+    /// 由 y 最小（其次 x 最小）的顶点与其相邻两顶点的方向决定，该顶点必为凸顶点。
+    /// 对简单多边形，结果与有向面积的符号一致；对自相交的多边形则不一定，此时请使用 [`Polygon::signed_area`].
+    ///
+    /// 顶点少于三个，或该顶点处的两条边共线（退化）时返回 `None`.
+    pub fn winding<T>(&self) -> Option<Winding>
+    where
+        P: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let points: Vec<Point<T>> = self.points.iter().map(get).collect();
+        let n = points.len();
+        let i = (0..n).min_by(|&a, &b| {
+            let (a, b) = (points[a], points[b]);
+            a.y.partial_cmp(&b.y)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal))
+        })?;
+        let v = points[i];
+        // 跳过与该顶点重合的相邻顶点。
+        let prev = (1..n).map(|k| points[(i + n - k) % n]).find(|p| *p != v)?;
+        let next = (1..n).map(|k| points[(i + k) % n]).find(|p| *p != v)?;
+        match T::orient2d(prev, v, next) {
+            Ordering::Greater => Some(Winding::CounterClockwise),
+            Ordering::Less => Some(Winding::Clockwise),
+            Ordering::Equal => None,
+        }
+    }
+    /// 使环绕方向为 `winding`, 必要时反转顶点顺序。[`Polygon::winding`] 为 `None` 时不变。
+    pub fn set_winding<T>(&mut self, winding: Winding)
+    where
+        P: Point2D<T>,
+        T: ExactPredicates,
+    {
+        if self.winding::<T>() == Some(winding.reverse()) {
+            self.reverse();
+        }
+    }
+    /// 是否为凸多边形。允许共线的相邻边，但不允许折返；所有顶点共线时不是凸多边形。
+    pub fn is_convex<T>(&self) -> bool
+    where
+        P: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let points = ring(&self.points);
+        let n = points.len();
+        if n < 3 {
+            return false;
+        }
+        let mut turn = Ordering::Equal;
+        // 边的 x, y 方向各自最多变化两次，否则多边形绕了不止一圈（如五角星）。
+        let mut sign_changes = [0; 2];
+        let mut last_sign = [Ordering::Equal; 2];
+        for i in 0..n {
+            let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+            if a == b || folds_back(a, b, c) {
+                return false;
+            }
+            match (turn, T::orient2d(a, b, c)) {
+                (_, Ordering::Equal) => {}
+                (Ordering::Equal, o) => turn = o,
+                (t, o) if t != o => return false,
+                _ => {}
+            }
+            for (k, (d, changes)) in [(b.x, a.x), (b.y, a.y)]
+                .into_iter()
+                .map(|(b, a)| b.partial_cmp(&a).unwrap_or(Ordering::Equal))
+                .zip(&mut sign_changes)
+                .enumerate()
+            {
+                if d != Ordering::Equal {
+                    if last_sign[k] != Ordering::Equal && last_sign[k] != d {
+                        *changes += 1;
+                    }
+                    last_sign[k] = d;
+                }
+            }
+        }
+        turn != Ordering::Equal && sign_changes.iter().all(|&c| c <= 2)
+    }
+    /// 是否为简单多边形，即边之间除相邻边的公共顶点外没有公共点。时间复杂度为 O(n²).
+    pub fn is_simple<T>(&self) -> bool
+    where
+        P: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let points = ring(&self.points);
+        let n = points.len();
+        if n < 3 {
+            return false;
+        }
+        let edge = |i: usize| (points[i], points[(i + 1) % n]);
+        for i in 0..n {
+            let (a, b) = edge(i);
+            if a == b || folds_back(a, b, points[(i + 2) % n]) {
+                return false;
+            }
+            // 与第 i 条边不相邻的边为 i + 2 ..= i + n - 2.
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+                let (c, d) = edge(j);
+                if segments_intersect(a, b, c, d) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+    /// 环绕数：边界绕该点逆时针转过的圈数。点在边界上时结果不确定。
+    pub fn winding_number<T, Q>(&self, p: &Q) -> i32
+    where
+        P: Point2D<T>,
+        Q: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let p = get(p);
+        let mut wn = 0;
+        for (a, b) in self.edges() {
+            let (a, b) = (get(a), get(b));
+            if a.y <= p.y {
+                if b.y > p.y && T::orient2d(a, b, p) == Ordering::Greater {
+                    wn += 1;
+                }
+            } else if b.y <= p.y && T::orient2d(a, b, p) == Ordering::Less {
+                wn -= 1;
+            }
+        }
+        wn
+    }
+    /// 点是否在多边形内部，按 `rule` 判断。点在边界上时结果不确定。
+    #[inline]
+    pub fn contains<T, Q>(&self, p: &Q, rule: FillRule) -> bool
+    where
+        P: Point2D<T>,
+        Q: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let wn = self.winding_number(p);
+        match rule {
+            FillRule::EvenOdd => wn % 2 != 0,
+            FillRule::NonZero => wn != 0,
+        }
+    }
+}
+
+/// 顶点坐标。首尾顶点重复时去掉最后一个。
+fn ring<T: Copy + PartialEq, P: Point2D<T>>(points: &[P]) -> Vec<Point<T>> {
+    let mut points: Vec<Point<T>> = points.iter().map(get).collect();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 五角星，中心处的环绕数为 2.
+    fn pentagram() -> Polygon<(f64, f64)> {
+        let outer: Vec<(f64, f64)> = (0..5)
+            .map(|k| {
+                let a = std::f64::consts::FRAC_PI_2 + k as f64 * std::f64::consts::TAU / 5.0;
+                (a.cos(), a.sin())
+            })
+            .collect();
+        (0..5).map(|k| outer[k * 2 % 5]).collect()
+    }
+
+    #[test]
+    fn even_odd_and_nonzero_on_self_overlapping_star() {
+        let star = pentagram();
+        let center = (0.0, 0.0);
+        assert_eq!(star.winding_number(&center), 2);
+        assert!(!star.contains(&center, FillRule::EvenOdd));
+        assert!(star.contains(&center, FillRule::NonZero));
+        // 星的尖角只被绕过一次。
+        let tip = (0.0, 0.9);
+        assert_eq!(star.winding_number(&tip), 1);
+        assert!(star.contains(&tip, FillRule::EvenOdd));
+        assert!(star.contains(&tip, FillRule::NonZero));
+        let outside = (0.9, 0.9);
+        assert!(!star.contains(&outside, FillRule::EvenOdd));
+        assert!(!star.contains(&outside, FillRule::NonZero));
+        assert!(!star.is_simple());
+        assert!(!star.is_convex());
+    }
+
+    #[test]
+    fn bow_tie_is_not_simple() {
+        let bow_tie = Polygon::new(vec![(0, 0), (2, 2), (2, 0), (0, 2)]);
+        assert!(!bow_tie.is_simple());
+        assert!(!bow_tie.is_convex());
+        assert_eq!(bow_tie.twice_signed_area(), 0i128);
+        let square = Polygon::new(vec![(0, 0), (2, 0), (2, 2), (0, 2)]);
+        assert!(square.is_simple());
+        assert!(square.is_convex());
+    }
+
+    #[test]
+    fn winding_direction() {
+        let mut p = Polygon::new(vec![(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(p.winding(), Some(Winding::CounterClockwise));
+        assert_eq!(p.twice_signed_area(), 24i128);
+        p.reverse();
+        assert_eq!(p.winding(), Some(Winding::Clockwise));
+        assert_eq!(p.twice_signed_area(), -24i128);
+        p.set_winding(Winding::CounterClockwise);
+        assert_eq!(p.winding(), Some(Winding::CounterClockwise));
+
+        let f = Polygon::new(vec![[0.0, 0.0], [0.0, 3.0], [4.0, 3.0], [4.0, 0.0]]);
+        assert_eq!(f.winding(), Some(Winding::Clockwise));
+        assert_eq!(f.signed_area(), -12.0);
+        assert_eq!(f.area(), 12.0);
+        assert_eq!(Polygon::new(vec![(0, 0), (1, 1), (2, 2)]).winding(), None);
+    }
+
+    #[test]
+    fn integer_area_is_exact() {
+        // 坐标的乘积超出 `i64`, 面积也无法被 `f64` 精确表示。
+        let big = 1i64 << 40;
+        let p = Polygon::new(vec![
+            (big, big),
+            (big + 3, big),
+            (big + 3, big + 1),
+            (big, big + 1),
+        ]);
+        assert_eq!(p.twice_signed_area(), 6i128);
+        let glyph: Polygon<(u32, u32)> = Polygon::new(vec![(0, 0), (0, 7), (5, 7), (5, 0)]);
+        assert_eq!(glyph.twice_signed_area(), -70i128);
+        assert_eq!(glyph.winding(), Some(Winding::Clockwise));
+    }
+
+    #[test]
+    fn repeated_closing_vertex() {
+        let closed = Polygon::new(vec![(0, 0), (2, 0), (2, 2), (0, 2), (0, 0)]);
+        assert!(closed.is_simple());
+        assert!(closed.is_convex());
+        assert_eq!(closed.twice_signed_area(), 8i128);
+        assert_eq!(closed.winding(), Some(Winding::CounterClockwise));
+        assert!(closed.contains(&(1, 1), FillRule::EvenOdd));
+        let bow_tie = Polygon::new(vec![(0, 0), (2, 2), (2, 0), (0, 2), (0, 0)]);
+        assert!(!bow_tie.is_simple());
+    }
+}
@@ -0,0 +1,97 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    line_2d::segments_intersect,
+    point_2d::{get, Point, Point2D},
+    polygon_2d::{impl_point_container, Polygon},
+    predicates::ExactPredicates,
+};
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// # [`Polyline`]
+/// 折线，依次连接各顶点，首尾不相连。
+///
+/// 顶点可以是任意实现了 [`Point2D`] 的类型，如 `Vec<glam::Vec2>` 可以直接包装，无需复制。
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Polyline<P> {
+    pub points: Vec<P>,
+}
+impl_point_container!(Polyline);
+
+impl<P> Polyline<P> {
+    /// 各条边的两个端点。
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = (&P, &P)> {
+        self.points.windows(2).map(|w| (&w[0], &w[1]))
+    }
+    /// 首尾相连，转换为多边形。
+    #[inline]
+    pub fn into_polygon(self) -> Polygon<P> {
+        Polygon::new(self.points)
+    }
+    /// 总长度。
+    pub fn length<T>(&self) -> T
+    where
+        P: Point2D<T>,
+        T: Float,
+    {
+        self.edges()
+            .map(|(a, b)| (get(b) - get(a)).length())
+            .fold(T::zero(), |acc, l| acc + l)
+    }
+    /// 是否没有自相交，即不相邻的边没有公共点，相邻的边只有公共端点。
+    ///
+    /// 首尾两条边在端点处相接也视作自相交，闭合的折线请使用 [`Polygon`]. 时间复杂度为 O(n²).
+    pub fn is_simple<T>(&self) -> bool
+    where
+        P: Point2D<T>,
+        T: ExactPredicates,
+    {
+        let points: Vec<Point<T>> = self.points.iter().map(get).collect();
+        let n = points.len();
+        if n < 2 {
+            return true;
+        }
+        if points.windows(3).any(|w| folds_back(w[0], w[1], w[2])) {
+            return false;
+        }
+        if points.windows(2).any(|w| w[0] == w[1]) {
+            return false;
+        }
+        (0..n - 1).all(|i| {
+            (i + 2..n - 1)
+                .all(|j| !segments_intersect(points[i], points[i + 1], points[j], points[j + 1]))
+        })
+    }
+}
+
+/// 相邻的边 `ab`, `bc` 是否共线且方向相反，即除公共端点外还有重叠部分。
+///
+/// 共线时只需比较坐标的大小关系，无需做减法，对无符号整数同样适用。
+#[inline]
+pub(crate) fn folds_back<T: ExactPredicates>(a: Point<T>, b: Point<T>, c: Point<T>) -> bool {
+    let opposite = |a: T, b: T, c: T| (a < b && c < b) || (a > b && c > b);
+    T::orient2d(a, b, c) == Ordering::Equal && (opposite(a.x, b.x, c.x) || opposite(a.y, b.y, c.y))
+}
@@ -0,0 +1,593 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 鲁棒的几何谓词。
+//!
+//! [`orient2d`] 判断三点的方向，[`incircle`] 判断一点是否在三点确定的圆内。
+//! 二者都只关心行列式的符号，而浮点数直接计算的结果在接近共线（共圆）时可能是错误的，
+//! 这会导致凸包、多边形判断等算法得到自相矛盾的结果。
+//!
+//! 本模块对除 `i128` 与 `u128` 以外的所有基本数值类型给出精确的结果：
+//!
+//! - 位宽较小的整数拓宽为 `i128` 计算；
+//! - 浮点数先以误差界过滤，只有结果不确定时才以浮点数展开（Shewchuk 的方法）精确计算；
+//! - 64 位整数拆分为两个浮点数之和，同样以浮点数展开精确计算。
+//!
+//! 浮点数的计算假定中间结果不会上溢或下溢；含 `NaN` 或无穷时结果没有意义。
+
+use crate::point_2d::{get, Point, Point2D};
+use std::cmp::Ordering;
+
+/// 三点的方向。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Orientation {
+    /// 顺时针，即第三点在前两点所确定的有向直线的右侧（y 轴向上）。
+    Clockwise,
+    /// 共线。
+    Collinear,
+    /// 逆时针，即第三点在前两点所确定的有向直线的左侧（y 轴向上）。
+    CounterClockwise,
+}
+
+impl Orientation {
+    /// 由行列式的符号得到方向，正为逆时针。
+    #[inline]
+    pub fn from_sign(sign: Ordering) -> Self {
+        match sign {
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+            Ordering::Greater => Orientation::CounterClockwise,
+        }
+    }
+    /// 相反的方向，共线时不变。
+    #[inline]
+    pub fn reverse(self) -> Self {
+        match self {
+            Orientation::Clockwise => Orientation::CounterClockwise,
+            Orientation::Collinear => Orientation::Collinear,
+            Orientation::CounterClockwise => Orientation::Clockwise,
+        }
+    }
+}
+
+/// 可以精确计算几何谓词的数值类型，已为除 `i128` 与 `u128` 以外的所有基本数值类型实现。
+pub trait ExactPredicates: Copy + PartialOrd {
+    /// 行列式 `(a - c) × (b - c)` 的符号，为正时 `a, b, c` 逆时针排列。
+    fn orient2d(a: Point<Self>, b: Point<Self>, c: Point<Self>) -> Ordering;
+    /// 行列式
+    ///
+    /// ```text
+    /// | ax - dx  ay - dy  (ax - dx)² + (ay - dy)² |
+    /// | bx - dx  by - dy  (bx - dx)² + (by - dy)² |
+    /// | cx - dx  cy - dy  (cx - dx)² + (cy - dy)² |
+    /// ```
+    ///
+    /// 的符号。`a, b, c` 逆时针排列时，为正表示 `d` 在圆内。
+    fn incircle(a: Point<Self>, b: Point<Self>, c: Point<Self>, d: Point<Self>) -> Ordering;
+}
+
+/// `a, b, c` 三点的方向，结果是精确的。
+#[inline]
+pub fn orient2d<T, A, B, C>(a: &A, b: &B, c: &C) -> Orientation
+where
+    T: ExactPredicates,
+    A: Point2D<T>,
+    B: Point2D<T>,
+    C: Point2D<T>,
+{
+    Orientation::from_sign(T::orient2d(get(a), get(b), get(c)))
+}
+
+/// `d` 与过 `a, b, c` 三点的圆的位置关系，结果是精确的。
+///
+/// `a, b, c` 逆时针排列时，`Greater` 表示在圆内，`Less` 表示在圆外，`Equal` 表示四点共圆；
+/// 顺时针排列时相反。`a, b, c` 共线时，圆退化为直线，结果表示 `d` 在直线的哪一侧。
+#[inline]
+pub fn incircle<T, A, B, C, D>(a: &A, b: &B, c: &C, d: &D) -> Ordering
+where
+    T: ExactPredicates,
+    A: Point2D<T>,
+    B: Point2D<T>,
+    C: Point2D<T>,
+    D: Point2D<T>,
+{
+    T::incircle(get(a), get(b), get(c), get(d))
+}
+
+/// 对于整数，拓宽为 `i128` 后可以精确计算。
+macro_rules! impl_wide_orient2d {
+    ($a:ident, $b:ident, $c:ident) => {{
+        let (ax, ay) = ($a.x as i128, $a.y as i128);
+        let (bx, by) = ($b.x as i128, $b.y as i128);
+        let (cx, cy) = ($c.x as i128, $c.y as i128);
+        ((ax - cx) * (by - cy)).cmp(&((ay - cy) * (bx - cx)))
+    }};
+}
+
+/// 8 位与 16 位整数：两个谓词都以 `i128` 精确计算。
+macro_rules! impl_narrow_int {
+    ($($t:ty),*) => {
+        $(
+            impl ExactPredicates for $t {
+                #[inline]
+                fn orient2d(a: Point<Self>, b: Point<Self>, c: Point<Self>) -> Ordering {
+                    impl_wide_orient2d!(a, b, c)
+                }
+                #[inline]
+                fn incircle(a: Point<Self>, b: Point<Self>, c: Point<Self>, d: Point<Self>) -> Ordering {
+                    let (dx, dy) = (d.x as i128, d.y as i128);
+                    let [adx, ady, bdx, bdy, cdx, cdy] = [
+                        a.x as i128 - dx, a.y as i128 - dy,
+                        b.x as i128 - dx, b.y as i128 - dy,
+                        c.x as i128 - dx, c.y as i128 - dy,
+                    ];
+                    let alift = adx * adx + ady * ady;
+                    let blift = bdx * bdx + bdy * bdy;
+                    let clift = cdx * cdx + cdy * cdy;
+                    let det = alift * (bdx * cdy - cdx * bdy)
+                        + blift * (cdx * ady - adx * cdy)
+                        + clift * (adx * bdy - bdx * ady);
+                    det.cmp(&0)
+                }
+            }
+        )*
+    };
+}
+impl_narrow_int!(i8, i16, u8, u16);
+
+/// 32 位整数：`orient2d` 以 `i128` 精确计算；`incircle` 的乘积可能超出 `i128`,
+/// 但坐标可以精确地表示为 `f64`, 故使用浮点数的方法。
+macro_rules! impl_int32 {
+    ($($t:ty),*) => {
+        $(
+            impl ExactPredicates for $t {
+                #[inline]
+                fn orient2d(a: Point<Self>, b: Point<Self>, c: Point<Self>) -> Ordering {
+                    impl_wide_orient2d!(a, b, c)
+                }
+                #[inline]
+                fn incircle(a: Point<Self>, b: Point<Self>, c: Point<Self>, d: Point<Self>) -> Ordering {
+                    let f = |p: Point<Self>| p.map(f64::from);
+                    f64::incircle(f(a), f(b), f(c), f(d))
+                }
+            }
+        )*
+    };
+}
+impl_int32!(i32, u32);
+
+/// 64 位整数：坐标不能精确地表示为 `f64`, 故拆分为两个 `f64` 之和，以浮点数展开精确计算。
+macro_rules! impl_int64 {
+    ($($t:ty),*) => {
+        $(
+            impl ExactPredicates for $t {
+                #[inline]
+                fn orient2d(a: Point<Self>, b: Point<Self>, c: Point<Self>) -> Ordering {
+                    let f = |p: Point<Self>| p.map(|v| split_i128(v as i128));
+                    orient2d_exact(f(a), f(b), f(c))
+                }
+                #[inline]
+                fn incircle(a: Point<Self>, b: Point<Self>, c: Point<Self>, d: Point<Self>) -> Ordering {
+                    let f = |p: Point<Self>| p.map(|v| split_i128(v as i128));
+                    incircle_exact(f(a), f(b), f(c), f(d))
+                }
+            }
+        )*
+    };
+}
+impl_int64!(i64, u64, isize, usize);
+
+impl ExactPredicates for f64 {
+    fn orient2d(a: Point<Self>, b: Point<Self>, c: Point<Self>) -> Ordering {
+        let detleft = (a.x - c.x) * (b.y - c.y);
+        let detright = (a.y - c.y) * (b.x - c.x);
+        let det = detleft - detright;
+        let errbound = CCW_ERR_BOUND_A * (detleft.abs() + detright.abs());
+        if det > errbound || -det > errbound {
+            return sign(det);
+        }
+        let f = |p: Point<Self>| p.map(|v| vec![v]);
+        orient2d_exact(f(a), f(b), f(c))
+    }
+    fn incircle(a: Point<Self>, b: Point<Self>, c: Point<Self>, d: Point<Self>) -> Ordering {
+        let (adx, ady) = (a.x - d.x, a.y - d.y);
+        let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+        let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+        let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+        let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+        let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+        let alift = adx * adx + ady * ady;
+        let blift = bdx * bdx + bdy * bdy;
+        let clift = cdx * cdx + cdy * cdy;
+        let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+        let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+            + (cdxady.abs() + adxcdy.abs()) * blift
+            + (adxbdy.abs() + bdxady.abs()) * clift;
+        let errbound = ICC_ERR_BOUND_A * permanent;
+        if det > errbound || -det > errbound {
+            return sign(det);
+        }
+        let f = |p: Point<Self>| p.map(|v| vec![v]);
+        incircle_exact(f(a), f(b), f(c), f(d))
+    }
+}
+
+/// `f32` 可以精确地转换为 `f64`, 故使用 `f64` 的方法。
+impl ExactPredicates for f32 {
+    #[inline]
+    fn orient2d(a: Point<Self>, b: Point<Self>, c: Point<Self>) -> Ordering {
+        let f = |p: Point<Self>| p.map(f64::from);
+        f64::orient2d(f(a), f(b), f(c))
+    }
+    #[inline]
+    fn incircle(a: Point<Self>, b: Point<Self>, c: Point<Self>, d: Point<Self>) -> Ordering {
+        let f = |p: Point<Self>| p.map(f64::from);
+        f64::incircle(f(a), f(b), f(c), f(d))
+    }
+}
+
+/// 舍入误差的上界，即 `f64` 的单位舍入 2⁻⁵³.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+/// `orient2d` 直接计算时的相对误差界，见 Shewchuk (1997).
+const CCW_ERR_BOUND_A: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+/// `incircle` 直接计算时的相对误差界，见 Shewchuk (1997).
+const ICC_ERR_BOUND_A: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+#[inline]
+fn sign(x: f64) -> Ordering {
+    x.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+}
+
+// 以下为浮点数展开的运算。一个展开是若干个互不重叠、按绝对值递增排列的 `f64`, 其精确值为各分量之和。
+
+/// 精确的加法：`a + b = x + y`.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+
+/// 精确的乘法：`a * b = x + y`.
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+/// 展开与一个数之和，去掉为零的分量。
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &e in e {
+        let (sum, err) = two_sum(q, e);
+        q = sum;
+        if err != 0.0 {
+            h.push(err);
+        }
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+/// 两个展开之和。
+pub(crate) fn add(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |h, &f| grow(&h, f))
+}
+
+/// 两个展开之差。
+pub(crate) fn sub(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |h, &f| grow(&h, -f))
+}
+
+/// 展开与一个数之积，去掉为零的分量。
+fn scale(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() * 2);
+    let mut push = |x: f64| {
+        if x != 0.0 {
+            h.push(x)
+        }
+    };
+    let Some((&first, rest)) = e.split_first() else {
+        return vec![0.0];
+    };
+    let (mut q, err) = two_product(first, b);
+    push(err);
+    for &e in rest {
+        let (hi, lo) = two_product(e, b);
+        let (sum, err) = two_sum(q, lo);
+        push(err);
+        let (sum, err) = two_sum(hi, sum);
+        push(err);
+        q = sum;
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+/// 两个展开之积。
+pub(crate) fn mul(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(vec![0.0], |h, &f| add(&h, &scale(e, f)))
+}
+
+/// 展开的符号，即绝对值最大的分量的符号。
+#[inline]
+pub(crate) fn expansion_sign(e: &[f64]) -> Ordering {
+    e.last().map_or(Ordering::Equal, |&x| sign(x))
+}
+
+/// 将整数精确地拆分为两个 `f64` 之和，结果为展开。
+#[inline]
+fn split_i128(v: i128) -> Vec<f64> {
+    let hi = v as f64;
+    let lo = (v - hi as i128) as f64;
+    grow(&[lo], hi)
+}
+
+fn orient2d_exact(a: Point<Vec<f64>>, b: Point<Vec<f64>>, c: Point<Vec<f64>>) -> Ordering {
+    let (acx, acy) = (sub(&a.x, &c.x), sub(&a.y, &c.y));
+    let (bcx, bcy) = (sub(&b.x, &c.x), sub(&b.y, &c.y));
+    expansion_sign(&sub(&mul(&acx, &bcy), &mul(&acy, &bcx)))
+}
+
+fn incircle_exact(
+    a: Point<Vec<f64>>,
+    b: Point<Vec<f64>>,
+    c: Point<Vec<f64>>,
+    d: Point<Vec<f64>>,
+) -> Ordering {
+    let (adx, ady) = (sub(&a.x, &d.x), sub(&a.y, &d.y));
+    let (bdx, bdy) = (sub(&b.x, &d.x), sub(&b.y, &d.y));
+    let (cdx, cdy) = (sub(&c.x, &d.x), sub(&c.y, &d.y));
+    let lift = |x: &[f64], y: &[f64]| add(&mul(x, x), &mul(y, y));
+    let cross = |x1: &[f64], y1: &[f64], x2: &[f64], y2: &[f64]| sub(&mul(x1, y2), &mul(x2, y1));
+    let det = add(
+        &add(
+            &mul(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy)),
+            &mul(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady)),
+        ),
+        &mul(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy)),
+    );
+    expansion_sign(&det)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 以 `i128` 计算的 `orient2d`, 作为标准答案。
+    fn orient2d_i128(a: (i128, i128), b: (i128, i128), c: (i128, i128)) -> Ordering {
+        ((a.0 - c.0) * (b.1 - c.1)).cmp(&((a.1 - c.1) * (b.0 - c.0)))
+    }
+
+    /// 简单的线性同余生成器，避免引入额外的依赖。
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self) -> u64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            self.0 >> 33
+        }
+    }
+
+    #[test]
+    fn orient2d_near_collinear_f64() {
+        // 经典的测试：在 (0.5, 0.5) 附近逐个 ulp 移动，判断与 (12, 12), (24, 24) 的方向。
+        // 所有坐标乘以 2⁵³ 后都是整数，可以用 `i128` 精确计算。
+        let ulp = f64::EPSILON / 2.0;
+        let scale = (1u64 << 53) as f64;
+        let exact = |v: f64| (v * scale) as i128;
+        let (b, c) = (Point::new(12.0, 12.0), Point::new(24.0, 24.0));
+        let mut collinear = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = Point::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let expected = orient2d_i128(
+                    (exact(a.x), exact(a.y)),
+                    (exact(b.x), exact(b.y)),
+                    (exact(c.x), exact(c.y)),
+                );
+                assert_eq!(f64::orient2d(a, b, c), expected, "{a:?}");
+                collinear += (expected == Ordering::Equal) as usize;
+            }
+        }
+        assert!(collinear > 0);
+    }
+
+    #[test]
+    fn orient2d_near_collinear_f32() {
+        let ulp = f32::EPSILON / 2.0;
+        let scale = (1u64 << 24) as f32;
+        let exact = |v: f32| (v as f64 * scale as f64) as i128;
+        let (b, c) = (Point::new(12.0f32, 12.0), Point::new(24.0f32, 24.0));
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = Point::new(0.5 + i as f32 * ulp, 0.5 + j as f32 * ulp);
+                let expected = orient2d_i128(
+                    (exact(a.x), exact(a.y)),
+                    (exact(b.x), exact(b.y)),
+                    (exact(c.x), exact(c.y)),
+                );
+                assert_eq!(f32::orient2d(a, b, c), expected, "{a:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn orient2d_near_collinear_i64() {
+        // 坐标的绝对值小于 2⁶¹, 差的乘积不会超出 `i128`.
+        let mut rng = Lcg(1);
+        let mut random = || rng.next() as i64 - (1 << 30);
+        for _ in 0..10000 {
+            let a = (random() << 30, random() << 30);
+            let d = (random(), random());
+            let (s, t) = (random(), random());
+            let perturb = (random() % 2, random() % 2);
+            let b = (a.0 + d.0 * s, a.1 + d.1 * s);
+            let c = (a.0 + d.0 * t + perturb.0, a.1 + d.1 * t + perturb.1);
+            let expected = orient2d_i128(
+                (a.0 as i128, a.1 as i128),
+                (b.0 as i128, b.1 as i128),
+                (c.0 as i128, c.1 as i128),
+            );
+            let f = |p: (i64, i64)| Point::new(p.0, p.1);
+            assert_eq!(
+                i64::orient2d(f(a), f(b), f(c)),
+                expected,
+                "{a:?} {b:?} {c:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn orient2d_extremes() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let p = |x: i64, y: i64| Point::new(x, y);
+        assert_eq!(
+            i64::orient2d(p(min, min), p(max, max), p(0, 0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            i64::orient2d(p(min, min), p(max, max), p(-1, -1)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            i64::orient2d(p(min, min), p(max, max), p(0, 1)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            i64::orient2d(p(min, min), p(max, max), p(1, 0)),
+            Ordering::Less
+        );
+        assert_eq!(
+            i64::orient2d(p(min, max), p(max, min), p(max, max)),
+            Ordering::Greater
+        );
+
+        let max = u64::MAX;
+        let p = |x: u64, y: u64| Point::new(x, y);
+        assert_eq!(
+            u64::orient2d(p(0, 0), p(max, max), p(max - 1, max)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            u64::orient2d(p(0, 0), p(max, max), p(max, max - 1)),
+            Ordering::Less
+        );
+        // 直线 x + y = u64::MAX 上的点。
+        let h = max / 2;
+        assert_eq!(
+            u64::orient2d(p(max, 0), p(0, max), p(h, h + 1)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            u64::orient2d(p(max, 0), p(0, max), p(h, h)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            u64::orient2d(p(max, 0), p(0, max), p(h + 1, h + 1)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn incircle_cocircular() {
+        // 半径为 5 的圆上的整点，`a, b, c` 逆时针排列。
+        let (a, b, c) = ((5, 0), (0, 5), (-3, 4));
+        let on = [(-5, 0), (3, -4), (-4, -3), (4, 3)];
+        let p = |(x, y): (i64, i64)| Point::new(x, y);
+        for d in on {
+            assert_eq!(
+                i32::incircle(p32(a), p32(b), p32(c), p32(d)),
+                Ordering::Equal
+            );
+            assert_eq!(i64::incircle(p(a), p(b), p(c), p(d)), Ordering::Equal);
+            assert_eq!(i64::incircle(p(a), p(c), p(b), p(d)), Ordering::Equal);
+        }
+        assert_eq!(
+            i64::incircle(p(a), p(b), p(c), p((0, 0))),
+            Ordering::Greater
+        );
+        assert_eq!(i64::incircle(p(a), p(b), p(c), p((5, 1))), Ordering::Less);
+        assert_eq!(i64::incircle(p(a), p(c), p(b), p((0, 0))), Ordering::Less);
+
+        // 平移到很大的坐标，乘积超出 `i128`.
+        let o = 1i64 << 61;
+        let q = |(x, y): (i64, i64)| Point::new(x + o, y + o);
+        for d in on {
+            assert_eq!(i64::incircle(q(a), q(b), q(c), q(d)), Ordering::Equal);
+        }
+        assert_eq!(
+            i64::incircle(q(a), q(b), q(c), q((0, 0))),
+            Ordering::Greater
+        );
+        assert_eq!(i64::incircle(q(a), q(b), q(c), q((5, 1))), Ordering::Less);
+
+        // 浮点数：半径为 5/8 的圆上的点平移 10⁶ 后仍可精确表示，但直接计算会有舍入误差。
+        let ulp = 2f64.powi(-33);
+        let f = |(x, y): (i64, i64)| Point::new(x as f64 / 8.0 + 1e6, y as f64 / 8.0 + 1e6);
+        for d in on {
+            assert_eq!(f64::incircle(f(a), f(b), f(c), f(d)), Ordering::Equal);
+            let g = |p: Point<f64>| p.map(|v| v as f32);
+            let (fa, fb, fc, fd) = (g(f(a)), g(f(b)), g(f(c)), g(f(d)));
+            assert_eq!(f32::incircle(fa, fb, fc, fd), Ordering::Equal);
+        }
+        let d = f((-5, 0));
+        let inside = Point::new(d.x + ulp, d.y);
+        let outside = Point::new(d.x - ulp, d.y);
+        assert_eq!(f64::incircle(f(a), f(b), f(c), inside), Ordering::Greater);
+        assert_eq!(f64::incircle(f(a), f(b), f(c), outside), Ordering::Less);
+    }
+
+    fn p32((x, y): (i64, i64)) -> Point<i32> {
+        Point::new(x as i32, y as i32)
+    }
+
+    #[test]
+    fn public_functions_accept_any_point_type() {
+        assert_eq!(
+            orient2d(&(0.0, 0.0), &[1.0, 0.0], &Point::new(0.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orient2d(&(0u8, 0), &(1, 1), &(2, 2)),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            orient2d(&(0i16, 0), &(0, 1), &(1, 0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            incircle(&(0, 0), &(2, 0), &(0, 2), &(1, 1)),
+            Ordering::Greater
+        );
+    }
+}
@@ -0,0 +1,201 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    point_2d::{get, Point, Point2D},
+    rect_2d::Rect,
+};
+use std::ops::Sub;
+
+/// 轴对齐包围盒的累加器，可以逐个添加点，也可以与其他累加器合并（如合并多个线程各自的结果）。
+///
+/// 任一坐标无法与自身比较（如 `NaN`）的点会被忽略。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct BoundingBox<T> {
+    corners: Option<(Point<T>, Point<T>)>,
+}
+impl<T> Default for BoundingBox<T> {
+    #[inline]
+    fn default() -> Self {
+        BoundingBox::new()
+    }
+}
+impl<T> BoundingBox<T> {
+    /// 不包含任何点的累加器。
+    #[inline]
+    pub const fn new() -> Self {
+        BoundingBox { corners: None }
+    }
+    /// 是否尚未包含任何点。
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.corners.is_none()
+    }
+}
+impl<T: Copy + PartialOrd> BoundingBox<T> {
+    /// 添加一个点。
+    #[inline]
+    pub fn add<P: Point2D<T>>(&mut self, p: &P) {
+        let (x, y) = (*p.rx(), *p.ry());
+        if x.partial_cmp(&x).is_none() || y.partial_cmp(&y).is_none() {
+            return;
+        }
+        let p = Point { x, y };
+        self.add_corners(p, p);
+    }
+    #[inline]
+    fn add_corners(&mut self, min: Point<T>, max: Point<T>) {
+        match &mut self.corners {
+            None => self.corners = Some((min, max)),
+            Some((lo, hi)) => {
+                if min.x < lo.x {
+                    lo.x = min.x;
+                }
+                if min.y < lo.y {
+                    lo.y = min.y;
+                }
+                if max.x > hi.x {
+                    hi.x = max.x;
+                }
+                if max.y > hi.y {
+                    hi.y = max.y;
+                }
+            }
+        }
+    }
+    /// 合并另一个累加器中的点。
+    #[inline]
+    pub fn merge(&mut self, other: &BoundingBox<T>) {
+        if let Some((min, max)) = other.corners {
+            self.add_corners(min, max);
+        }
+    }
+    /// 各坐标的最小值。
+    #[inline]
+    pub fn min(&self) -> Option<Point<T>> {
+        self.corners.map(|(min, _)| min)
+    }
+    /// 各坐标的最大值。
+    #[inline]
+    pub fn max(&self) -> Option<Point<T>> {
+        self.corners.map(|(_, max)| max)
+    }
+    /// 最小与最大角点。
+    #[inline]
+    pub fn corners(&self) -> Option<(Point<T>, Point<T>)> {
+        self.corners
+    }
+    /// 以最小与最大角点构造 [`Rect`].
+    ///
+    /// 注意 [`Rect`] 是左闭右开的，坐标取最大值的点不在其中。
+    #[inline]
+    pub fn to_rect(&self) -> Option<Rect<T>>
+    where
+        T: Sub<Output = T>,
+    {
+        self.corners.map(|(min, max)| Rect::from_min_max(min, max))
+    }
+}
+impl<T: Copy + PartialOrd, P: Point2D<T>> Extend<P> for BoundingBox<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|p| self.add(&p));
+    }
+}
+impl<T: Copy + PartialOrd, P: Point2D<T>> FromIterator<P> for BoundingBox<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut bounding_box = BoundingBox::new();
+        bounding_box.extend(iter);
+        bounding_box
+    }
+}
+
+/// [`bounding_box`] 接受的元素：实现了 [`Point2D`] 的点，或者它的引用。
+///
+/// `M` 为 [`ByValue`] 或 [`ByRef`], 只用于区分两种实现，由编译器推断，无需手动指定。
+pub trait BoundsItem<T, M> {
+    /// 复制出坐标值。
+    fn to_point(&self) -> Point<T>;
+}
+/// 见 [`BoundsItem`].
+pub enum ByValue {}
+/// 见 [`BoundsItem`].
+pub enum ByRef {}
+impl<T: Copy, P: Point2D<T>> BoundsItem<T, ByValue> for P {
+    #[inline]
+    fn to_point(&self) -> Point<T> {
+        get(self)
+    }
+}
+impl<T: Copy, P: Point2D<T>> BoundsItem<T, ByRef> for &P {
+    #[inline]
+    fn to_point(&self) -> Point<T> {
+        get(*self)
+    }
+}
+
+/// 计算一组点的轴对齐包围盒，返回最小与最大角点。
+///
+/// 点可以按值或按引用传入，如 `bounding_box(points.iter())` 或 `bounding_box(&points)`.
+/// 没有点时返回 `None`. 任一坐标无法与自身比较（如 `NaN`）的点会被忽略。
+#[inline]
+pub fn bounding_box<I, T, M>(points: I) -> Option<(Point<T>, Point<T>)>
+where
+    I: IntoIterator,
+    I::Item: BoundsItem<T, M>,
+    T: Copy + PartialOrd,
+{
+    let mut bounding_box = BoundingBox::new();
+    for p in points {
+        bounding_box.add(&p.to_point());
+    }
+    bounding_box.corners()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounding_box_of_borrowed_points() {
+        let points = vec![(3, -1), (0, 4), (-2, 2)];
+        let expected = Some((Point::new(-2, -1), Point::new(3, 4)));
+        assert_eq!(bounding_box(points.iter()), expected);
+        assert_eq!(bounding_box(&points), expected);
+        assert_eq!(
+            bounding_box(&points[..2]),
+            Some((Point::new(0, -1), Point::new(3, 4)))
+        );
+        assert_eq!(bounding_box(points), expected);
+        assert_eq!(bounding_box(Vec::<[f64; 2]>::new()), None);
+    }
+
+    #[test]
+    fn bounding_box_ignores_nan() {
+        let points = [[f64::NAN, 0.0], [1.0, 2.0], [-1.0, f64::NAN], [0.5, -3.0]];
+        assert_eq!(
+            bounding_box(&points),
+            Some((Point::new(0.5, -3.0), Point::new(1.0, 2.0)))
+        );
+    }
+}
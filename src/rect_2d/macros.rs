@@ -0,0 +1,112 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+#[macro_export]
+macro_rules! impl_rect2d {
+    (@ctor Struct, [$($f:tt),*], [$($v:expr),*]) => {
+        Self { $($f: $v),* }
+    };
+    (@ctor New, [$($f:tt),*], [$($v:expr),*]) => {
+        Self::new($($v),*)
+    };
+    (impl $(<$t1:ident$(, $other1:ident)*>)? Trait<$t2:ident> for $root:ident $(:: $idents:ident )*$(<$t3:ident$(, $other2:ident)*>)?$(where {$($case:tt)*})?, OriginSize($o:tt, $s:tt), $expr:tt) => {
+        impl$(<$t1$(,$other1)*>)? $crate::rect_2d::Rect2D<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)*)?{
+            #[inline]
+            fn into_rect(self) -> $crate::rect_2d::Rect<$t2> {
+                $crate::rect_2d::Rect {
+                    origin: $crate::point_2d::Point2D::into_point(self.$o),
+                    size: $crate::size_2d::Size2D::into_size(self.$s),
+                }
+            }
+            #[inline]
+            fn from_rect(r: $crate::rect_2d::Rect<$t2>) -> Self {
+                impl_rect2d!(@ctor $expr, [$o, $s], [
+                    $crate::point_2d::Point2D::from_point(r.origin),
+                    $crate::size_2d::Size2D::from_size(r.size)
+                ])
+            }
+        }
+    };
+    (impl $(<$t1:ident$(, $other1:ident)*>)? Trait<$t2:ident> for $root:ident $(:: $idents:ident )*$(<$t3:ident$(, $other2:ident)*>)?$(where {$($case:tt)*})?, MinMax($min:tt, $max:tt), $expr:tt) => {
+        impl$(<$t1$(,$other1)*>)? $crate::rect_2d::Rect2D<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)*)?{
+            #[inline]
+            fn into_rect(self) -> $crate::rect_2d::Rect<$t2> {
+                $crate::rect_2d::Rect::from_min_max(
+                    $crate::point_2d::Point2D::into_point(self.$min),
+                    $crate::point_2d::Point2D::into_point(self.$max),
+                )
+            }
+            #[inline]
+            fn from_rect(r: $crate::rect_2d::Rect<$t2>) -> Self {
+                impl_rect2d!(@ctor $expr, [$min, $max], [
+                    $crate::point_2d::Point2D::from_point(r.min()),
+                    $crate::point_2d::Point2D::from_point(r.max())
+                ])
+            }
+        }
+    };
+    (impl $(<$t1:ident$(, $other1:ident)*>)? Trait<$t2:ident> for $root:ident $(:: $idents:ident )*$(<$t3:ident$(, $other2:ident)*>)?$(where {$($case:tt)*})?, Bounds($x0:tt, $y0:tt, $x1:tt, $y1:tt), $expr:tt) => {
+        impl$(<$t1$(,$other1)*>)? $crate::rect_2d::Rect2D<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)*)?{
+            #[inline]
+            fn into_rect(self) -> $crate::rect_2d::Rect<$t2> {
+                $crate::rect_2d::Rect::from_min_max(
+                    $crate::point_2d::Point {
+                        x: self.$x0,
+                        y: self.$y0,
+                    },
+                    $crate::point_2d::Point {
+                        x: self.$x1,
+                        y: self.$y1,
+                    },
+                )
+            }
+            #[inline]
+            fn from_rect(r: $crate::rect_2d::Rect<$t2>) -> Self {
+                let (min, max) = (r.min(), r.max());
+                impl_rect2d!(@ctor $expr, [$x0, $y0, $x1, $y1], [min.x, min.y, max.x, max.y])
+            }
+        }
+    };
+    (impl $(<$t1:ident$(, $other1:ident)*>)? Trait<$t2:ident> for $root:ident $(:: $idents:ident )*$(<$t3:ident$(, $other2:ident)*>)?$(where {$($case:tt)*})?, Xywh($x:tt, $y:tt, $w:tt, $h:tt), $expr:tt) => {
+        impl$(<$t1$(,$other1)*>)? $crate::rect_2d::Rect2D<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)*)?{
+            #[inline]
+            fn into_rect(self) -> $crate::rect_2d::Rect<$t2> {
+                $crate::rect_2d::Rect {
+                    origin: $crate::point_2d::Point {
+                        x: self.$x,
+                        y: self.$y,
+                    },
+                    size: $crate::size_2d::Size {
+                        width: self.$w,
+                        height: self.$h,
+                    },
+                }
+            }
+            #[inline]
+            fn from_rect(r: $crate::rect_2d::Rect<$t2>) -> Self {
+                impl_rect2d!(@ctor $expr, [$x, $y, $w, $h], [r.origin.x, r.origin.y, r.size.width, r.size.height])
+            }
+        }
+    };
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>$(where {$($case:tt)*})?, $layout:ident($($f:tt),*), $expr:tt) => {
+        impl_rect2d!(impl<$t$(, $other)*> Trait<$t> for $root $(:: $idents )*<$t$(, $other)*>$(where {$($case)*})?, $layout($($f),*), $expr);
+    };
+}
@@ -0,0 +1,277 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod bounds;
+mod macros;
+pub mod rects;
+
+pub use bounds::*;
+
+use crate::{
+    point_2d::{PassVector, Point, Point2D},
+    size_2d::Size,
+};
+use num_traits::Num;
+use std::ops::{Add, Sub};
+
+/// 所有类似的矩形类型都应当实现该 trait.
+///
+/// 不同矩形类型的内部表示各不相同（原点与尺寸、最小与最大角点等），
+/// 故该 trait 不提供获取字段引用的函数。
+pub trait Rect2D<T> {
+    /// 转换为内建 [`Rect`] 类型。
+    fn into_rect(self) -> Rect<T>;
+    /// 通过内建 [`Rect`] 类型构造。
+    fn from_rect(rect_2d: Rect<T>) -> Self;
+}
+
+/// # [`Rect`]
+/// 内建 `Rect` 类型，用作矩形类型之间转换的的桥梁。
+///
+/// 以原点（最小角点）与尺寸表示，也可以通过 [`Rect::from_min_max`],
+/// [`Rect::min`] 与 [`Rect::max`] 以最小与最大角点表示。
+///
+/// 矩形是左闭右开的，即包含最小角点所在的边，不包含最大角点所在的边。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Rect<T> {
+    pub origin: Point<T>,
+    pub size: Size<T>,
+}
+impl<T> Rect2D<T> for Rect<T> {
+    #[inline]
+    fn into_rect(self) -> Rect<T> {
+        self
+    }
+    #[inline]
+    fn from_rect(rect_2d: Rect<T>) -> Self {
+        rect_2d
+    }
+}
+#[inline]
+fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+#[inline]
+fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+impl<T> Rect<T> {
+    #[inline]
+    pub fn new(origin: Point<T>, size: Size<T>) -> Rect<T> {
+        Rect { origin, size }
+    }
+    /// 通过最小与最大角点构造。
+    #[inline]
+    pub fn from_min_max(min: Point<T>, max: Point<T>) -> Rect<T>
+    where
+        T: Copy + Sub<Output = T>,
+    {
+        Rect {
+            origin: min,
+            size: Size::new(max.x - min.x, max.y - min.y),
+        }
+    }
+    #[inline]
+    pub fn from_rect_2d<I>(r: I) -> Self
+    where
+        I: Rect2D<T>,
+    {
+        r.into_rect()
+    }
+    #[inline]
+    pub fn into_rect_2d<O>(self) -> O
+    where
+        O: Rect2D<T>,
+    {
+        O::from_rect(self)
+    }
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Rect<U> {
+        Rect {
+            origin: self.origin.map(&f),
+            size: self.size.map(&f),
+        }
+    }
+    /// 最小角点，即原点。
+    #[inline]
+    pub fn min(&self) -> Point<T>
+    where
+        T: Copy,
+    {
+        self.origin
+    }
+    /// 最大角点，即原点加上尺寸。
+    #[inline]
+    pub fn max(&self) -> Point<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        Point::new(
+            self.origin.x + self.size.width,
+            self.origin.y + self.size.height,
+        )
+    }
+}
+impl<T: Copy + Num + PartialOrd> Rect<T> {
+    /// 宽度或高度不大于零时为空。
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+    /// 点是否在矩形内。包含最小角点所在的边，不包含最大角点所在的边。
+    #[inline]
+    pub fn contains<P: Point2D<T>>(&self, p: &P) -> bool {
+        let (min, max) = (self.min(), self.max());
+        let (x, y) = (*p.rx(), *p.ry());
+        min.x <= x && x < max.x && min.y <= y && y < max.y
+    }
+    /// 两矩形的交集，交集为空时返回 `None`.
+    #[inline]
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let (a_min, a_max, b_min, b_max) = (self.min(), self.max(), other.min(), other.max());
+        let min = Point::new(partial_max(a_min.x, b_min.x), partial_max(a_min.y, b_min.y));
+        let max = Point::new(partial_min(a_max.x, b_max.x), partial_min(a_max.y, b_max.y));
+        // 先比较再相减，避免无符号整数在不相交时溢出。
+        if min.x < max.x && min.y < max.y {
+            Some(Rect::from_min_max(min, max))
+        } else {
+            None
+        }
+    }
+    /// 同时包含两矩形的最小矩形。空矩形不参与计算。
+    #[inline]
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let (a_min, a_max, b_min, b_max) = (self.min(), self.max(), other.min(), other.max());
+        let min = Point::new(partial_min(a_min.x, b_min.x), partial_min(a_min.y, b_min.y));
+        let max = Point::new(partial_max(a_max.x, b_max.x), partial_max(a_max.y, b_max.y));
+        Rect::from_min_max(min, max)
+    }
+    /// 四周分别向外扩展 `dx` 与 `dy`, 为负时向内收缩。
+    #[inline]
+    pub fn inflate(&self, dx: T, dy: T) -> Rect<T> {
+        Rect {
+            origin: Point::new(self.origin.x - dx, self.origin.y - dy),
+            size: Size::new(self.size.width + dx + dx, self.size.height + dy + dy),
+        }
+    }
+    /// 平移。
+    #[inline]
+    pub fn translate<U: PassVector<T>>(&self, v: U) -> Rect<T> {
+        Rect {
+            origin: self.origin + v.pass(),
+            size: self.size,
+        }
+    }
+    /// 中心点。
+    #[inline]
+    pub fn center(&self) -> Point<T> {
+        let two = T::one() + T::one();
+        Point::new(
+            self.origin.x + self.size.width / two,
+            self.origin.y + self.size.height / two,
+        )
+    }
+    /// 四个角点，从最小角点开始，在 y 轴向上的坐标系中按逆时针排列。
+    #[inline]
+    pub fn corners(&self) -> [Point<T>; 4] {
+        let (min, max) = (self.min(), self.max());
+        [min, Point::new(max.x, min.y), max, Point::new(min.x, max.y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_of_disjoint_unsigned_rects() {
+        let a = Rect::new(Point::new(0u32, 0), Size::new(1, 1));
+        let b = Rect::new(Point::new(5u32, 5), Size::new(1, 1));
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(b.intersection(&a), None);
+        // 只在一个轴上分离。
+        let c = Rect::new(Point::new(0u32, 5), Size::new(3, 1));
+        assert_eq!(a.intersection(&c), None);
+    }
+
+    #[test]
+    fn intersection_of_disjoint_signed_rects() {
+        let a = Rect::new(Point::new(-3i32, -3), Size::new(2, 2));
+        let b = Rect::new(Point::new(5i32, 5), Size::new(1, 1));
+        assert_eq!(a.intersection(&b), None);
+        // 仅共享一条边时交集为空。
+        let c = Rect::new(Point::new(-1i32, -3), Size::new(2, 2));
+        assert_eq!(a.intersection(&c), None);
+    }
+
+    #[test]
+    fn intersection_of_overlapping_rects() {
+        let a = Rect::new(Point::new(0u32, 0), Size::new(4, 4));
+        let b = Rect::new(Point::new(2u32, 1), Size::new(5, 2));
+        let expected = Rect::new(Point::new(2, 1), Size::new(2, 2));
+        assert_eq!(a.intersection(&b), Some(expected));
+        assert_eq!(b.intersection(&a), Some(expected));
+    }
+
+    #[cfg(feature = "ttf-parser")]
+    #[test]
+    fn ttf_parser_rect_wider_than_i16() {
+        let r = ttf_parser::Rect {
+            x_min: -30000,
+            y_min: -1,
+            x_max: 30000,
+            y_max: 1,
+        };
+        let rect = r.into_rect();
+        assert_eq!(rect.size, Size::new(60000, 2));
+        assert_eq!(ttf_parser::Rect::from_rect(rect), r);
+        let big = Rect::new(Point::new(0i32, 0), Size::new(40000, 1));
+        assert_eq!(ttf_parser::Rect::from_rect(big).x_max, i16::MAX);
+    }
+
+    #[cfg(feature = "imageproc")]
+    #[test]
+    fn imageproc_rect_from_empty_rect() {
+        let r = imageproc::rect::Rect::from_rect(Rect::<i32>::default());
+        assert_eq!((r.left(), r.top(), r.width(), r.height()), (0, 0, 1, 1));
+        let negative = Rect::new(Point::new(3, -2), Size::new(-5, 4));
+        let r = imageproc::rect::Rect::from_rect(negative);
+        assert_eq!((r.left(), r.top(), r.width(), r.height()), (3, -2, 1, 4));
+        let r = imageproc::rect::Rect::from_rect(Rect::new(Point::new(1, 2), Size::new(3, 4)));
+        assert_eq!(r.into_rect(), Rect::new(Point::new(1, 2), Size::new(3, 4)));
+    }
+}
@@ -0,0 +1,96 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+#[allow(unused_imports)]
+use crate::impl_rect2d;
+
+#[cfg(feature = "euclid")]
+impl_rect2d!(euclid::Rect<T, U>, OriginSize(origin, size), New);
+#[cfg(feature = "euclid")]
+impl_rect2d!(euclid::Box2D<T, U> where {T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T>}, MinMax(min, max), New);
+
+#[cfg(feature = "ttf-parser")]
+impl_rect2d!(impl Trait<f32> for ttf_parser::RectF, Bounds(x_min, y_min, x_max, y_max), Struct);
+
+#[cfg(feature = "core-graphics")]
+use core_graphics::base::CGFloat;
+#[cfg(feature = "core-graphics")]
+impl_rect2d!(impl Trait<CGFloat> for core_graphics::geometry::CGRect, OriginSize(origin, size), Struct);
+
+#[cfg(windows)]
+#[cfg(feature = "windows")]
+pub mod windows_rects {
+    use crate::impl_rect2d;
+    impl_rect2d!(impl Trait<f32> for windows::Foundation::Rect, Xywh(X, Y, Width, Height), Struct);
+    impl_rect2d!(impl Trait<i32> for windows::Win32::Foundation::RECT, Bounds(left, top, right, bottom), Struct);
+    impl_rect2d!(impl Trait<f32> for windows::Win32::Graphics::Direct2D::Common::D2D_RECT_F, Bounds(left, top, right, bottom), Struct);
+    impl_rect2d!(impl Trait<u32> for windows::Win32::Graphics::Direct2D::Common::D2D_RECT_U, Bounds(left, top, right, bottom), Struct);
+}
+
+/// `imageproc` 的矩形以 `i32` 表示位置，以 `u32` 表示尺寸，故以 `i32` 转换，超出 `i32` 范围的尺寸取 `i32::MAX`.
+///
+/// [`imageproc::rect::Rect`] 不能表示空矩形，故由 [`Rect`](crate::rect_2d::Rect) 构造时，
+/// 不大于零的宽度或高度取 `1`.
+#[cfg(feature = "imageproc")]
+impl crate::rect_2d::Rect2D<i32> for imageproc::rect::Rect {
+    #[inline]
+    fn into_rect(self) -> crate::rect_2d::Rect<i32> {
+        crate::rect_2d::Rect::new(
+            crate::point_2d::Point::new(self.left(), self.top()),
+            crate::size_2d::Size::new(
+                i32::try_from(self.width()).unwrap_or(i32::MAX),
+                i32::try_from(self.height()).unwrap_or(i32::MAX),
+            ),
+        )
+    }
+    #[inline]
+    fn from_rect(rect_2d: crate::rect_2d::Rect<i32>) -> Self {
+        imageproc::rect::Rect::at(rect_2d.origin.x, rect_2d.origin.y).of_size(
+            u32::try_from(rect_2d.size.width).unwrap_or(0).max(1),
+            u32::try_from(rect_2d.size.height).unwrap_or(0).max(1),
+        )
+    }
+}
+
+/// `ttf_parser` 的矩形以 `i16` 表示边界，宽度与高度可能超出 `i16` 的范围，故以 `i32` 转换。
+///
+/// 由 [`Rect`](crate::rect_2d::Rect) 构造时，超出 `i16` 范围的边界取 `i16` 的最小值或最大值。
+#[cfg(feature = "ttf-parser")]
+impl crate::rect_2d::Rect2D<i32> for ttf_parser::Rect {
+    #[inline]
+    fn into_rect(self) -> crate::rect_2d::Rect<i32> {
+        crate::rect_2d::Rect::from_min_max(
+            crate::point_2d::Point::new(i32::from(self.x_min), i32::from(self.y_min)),
+            crate::point_2d::Point::new(i32::from(self.x_max), i32::from(self.y_max)),
+        )
+    }
+    #[inline]
+    fn from_rect(rect_2d: crate::rect_2d::Rect<i32>) -> Self {
+        let (min, max) = (rect_2d.min(), rect_2d.max());
+        let f = |v: i32| v.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        ttf_parser::Rect {
+            x_min: f(min.x),
+            y_min: f(min.y),
+            x_max: f(max.x),
+            y_max: f(max.y),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+#[macro_export]
+macro_rules! impl_size2d {
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>,Tuple, $expr:tt$(, $ww:tt, $hh:tt)?) => {
+        impl_size2d!($root$(::$idents)*<$t$(,$other)*>,0,1,$expr$(, $ww, $hh)?);
+    };
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>,Struct, $expr:tt$(, $ww:tt, $hh:tt)?) => {
+        impl_size2d!($root$(::$idents)*<$t$(,$other)*>,width,height,$expr$(, $ww, $hh)?);
+    };
+    (impl $(<$t1:ident$(, $other1:ident)*>)? Trait<$t2:ident> for $root:ident $(:: $idents:ident )*$(<$t3:ident$(, $other2:ident)*>)?$(where {$($case:tt)?*})?, $w:tt, $h:tt, $expr:tt$(, $ww:tt, $hh:tt)?) => {
+        impl$(<$t1$(,$other1)*>)? $crate::size_2d::Size2D<$t2> for $root$(::$idents)*$(<$t3$(,$other2)*>)?$(where $($case)?*)?{
+            #[inline]
+            fn rwidth(&self) -> &$t2 {
+                &self.$w
+            }
+            #[inline]
+            fn rheight(&self) -> &$t2 {
+                &self.$h
+            }
+            #[inline]
+            fn into_size(self) -> $crate::size_2d::Size<$t2> {
+                $crate::size_2d::Size {
+                    width: self.$w,
+                    height: self.$h,
+                }
+            }
+            #[inline]
+            fn from_size(s: $crate::size_2d::Size<$t2>) -> Self {
+                let wh = impl_size2d!($expr, $t2$(, $ww, $hh)?, $w, $h);
+                wh(s)
+            }
+        }
+    };
+    ($root:ident $(:: $idents:ident )*<$t:ident$(, $other:ident)*>, $w:tt, $h:tt, $expr:tt$(, $ww:tt, $hh:tt)?) => {
+        impl_size2d!(impl<$t$(, $other)*> Trait<$t> for $root $(:: $idents )*<$t$(, $other)*>, $w, $h, $expr$(, $ww, $hh)?);
+    };
+    (New, $t:ident$(, $ww:tt, $hh:tt)?)=>{
+        |s: $crate::size_2d::Size<$t>| -> Self{
+            Self::new(s.width, s.height)
+        }
+    };
+    (Tuple, $t:ident, $ww:tt, $hh:tt$(, $w:tt, $h:tt)?)=>{
+        |s: $crate::size_2d::Size<$t>| -> Self{
+            Self(s.width, s.height)
+        }
+    };
+    (Struct, $t:ident, $ww:tt, $hh:tt$(, $w:tt, $h:tt)?)=>{
+        |s: $crate::size_2d::Size<$t>| -> Self{
+            Self{$ww: s.width, $hh: s.height}
+        }
+    }
+}
@@ -0,0 +1,236 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod macros;
+pub mod sizes;
+
+use crate::point_2d::{Point, Point2D};
+use num_traits::{Float, Zero};
+use std::ops::{Deref, DerefMut, Mul};
+
+/// 所有类似的二维尺寸类型都应当实现该 trait.
+pub trait Size2D<T> {
+    /// 获取宽度的引用。
+    fn rwidth(&self) -> &T;
+    /// 获取高度的引用。
+    fn rheight(&self) -> &T;
+    /// 转换为内建 [`Size`] 类型。
+    fn into_size(self) -> Size<T>;
+    /// 通过内建 [`Size`] 类型构造。
+    fn from_size(size_2d: Size<T>) -> Self;
+    /// 使用宽度与高度的引用构造内建 [`Size`] 类型。
+    #[inline]
+    fn as_size(&self) -> Size<&T> {
+        Size {
+            width: self.rwidth(),
+            height: self.rheight(),
+        }
+    }
+}
+
+impl<T> Size2D<T> for (T, T) {
+    #[inline]
+    fn rwidth(&self) -> &T {
+        &self.0
+    }
+    #[inline]
+    fn rheight(&self) -> &T {
+        &self.1
+    }
+    #[inline]
+    fn into_size(self) -> Size<T> {
+        Size {
+            width: self.0,
+            height: self.1,
+        }
+    }
+    #[inline]
+    fn from_size(size_2d: Size<T>) -> Self {
+        (size_2d.width, size_2d.height)
+    }
+}
+impl<T> Size2D<T> for [T; 2] {
+    #[inline]
+    fn rwidth(&self) -> &T {
+        &self[0]
+    }
+    #[inline]
+    fn rheight(&self) -> &T {
+        &self[1]
+    }
+    #[inline]
+    fn into_size(self) -> Size<T> {
+        let [width, height] = self;
+        Size { width, height }
+    }
+    #[inline]
+    fn from_size(size_2d: Size<T>) -> Self {
+        [size_2d.width, size_2d.height]
+    }
+}
+/// # [`Size`]
+/// 内建 `Size` 类型，用作二维尺寸类型之间转换的的桥梁。
+///
+/// 用法与 [`Point`] 相同：先调用 [`Size2D::into_size`] 将一个尺寸类型转换为 `Size`,
+/// 再调用 [`Size::into_size_2d`] 将 `Size` 转换为另一个尺寸类型。
+///
+/// 对于把向量类型（如 `glam::UVec2`）当作尺寸使用的情况，
+/// 可以通过 [`Size::from_point_2d`] 与 [`Size::into_point_2d`] 转换。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Size<T> {
+    pub width: T,
+    pub height: T,
+}
+impl<T> Size2D<T> for Size<T> {
+    #[inline]
+    fn rwidth(&self) -> &T {
+        &self.width
+    }
+    #[inline]
+    fn rheight(&self) -> &T {
+        &self.height
+    }
+    #[inline]
+    fn into_size(self) -> Size<T> {
+        self
+    }
+    #[inline]
+    fn from_size(size_2d: Size<T>) -> Self {
+        size_2d
+    }
+}
+impl<T> Size<T> {
+    #[inline]
+    pub fn new(width: T, height: T) -> Size<T> {
+        Size::<T> { width, height }
+    }
+    #[inline]
+    pub const fn as_ref(&self) -> Size<&T> {
+        Size {
+            width: &self.width,
+            height: &self.height,
+        }
+    }
+    #[inline]
+    pub fn as_mut(&mut self) -> Size<&mut T> {
+        Size {
+            width: &mut self.width,
+            height: &mut self.height,
+        }
+    }
+    #[inline]
+    pub fn as_deref(&self) -> Size<&<T as Deref>::Target>
+    where
+        T: Deref,
+    {
+        Size {
+            width: &self.width,
+            height: &self.height,
+        }
+    }
+    #[inline]
+    pub fn as_deref_mut(&mut self) -> Size<&mut T::Target>
+    where
+        T: DerefMut,
+    {
+        Size {
+            width: &mut self.width,
+            height: &mut self.height,
+        }
+    }
+    #[inline]
+    pub fn from_size_2d<I>(s: I) -> Self
+    where
+        I: Size2D<T>,
+    {
+        s.into_size()
+    }
+    #[inline]
+    pub fn into_size_2d<O>(self) -> O
+    where
+        O: Size2D<T>,
+    {
+        O::from_size(self)
+    }
+    #[inline]
+    pub fn as_size_2d<'a, O>(&'a self) -> O
+    where
+        O: Size2D<&'a T>,
+    {
+        O::from_size(self.as_ref())
+    }
+    /// 将二维点类型的 x, y 坐标分别作为宽度与高度。
+    #[inline]
+    pub fn from_point_2d<I>(p: I) -> Self
+    where
+        I: Point2D<T>,
+    {
+        let Point { x, y } = p.into_point();
+        Size {
+            width: x,
+            height: y,
+        }
+    }
+    /// 将宽度与高度分别作为二维点类型的 x, y 坐标。
+    #[inline]
+    pub fn into_point_2d<O>(self) -> O
+    where
+        O: Point2D<T>,
+    {
+        O::from_point(Point {
+            x: self.width,
+            y: self.height,
+        })
+    }
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Size<U> {
+        Size {
+            width: f(self.width),
+            height: f(self.height),
+        }
+    }
+    /// 面积。
+    #[inline]
+    pub fn area(self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.width * self.height
+    }
+    /// 宽度或高度不大于零时为空。无法与零比较的值（如 `NaN`）同样视为空。
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    where
+        T: Zero + PartialOrd,
+    {
+        !(self.width > T::zero() && self.height > T::zero())
+    }
+    /// 宽高比，即宽度除以高度。
+    #[inline]
+    pub fn aspect_ratio(self) -> T
+    where
+        T: Float,
+    {
+        self.width / self.height
+    }
+}
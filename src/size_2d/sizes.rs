@@ -0,0 +1,41 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+#[allow(unused_imports)]
+use crate::impl_size2d;
+
+#[cfg(feature = "euclid")]
+impl_size2d!(euclid::Size2D<T, U>, Struct, New);
+
+#[cfg(feature = "core-graphics")]
+use core_graphics::base::CGFloat;
+#[cfg(feature = "core-graphics")]
+impl_size2d!(impl Trait<CGFloat> for core_graphics::geometry::CGSize, width, height, Struct);
+
+#[cfg(windows)]
+#[cfg(feature = "windows")]
+pub mod windows_sizes {
+    use crate::impl_size2d;
+    impl_size2d!(impl Trait<f32> for windows::Foundation::Size, Width, Height, Struct);
+    impl_size2d!(impl Trait<i32> for windows::Win32::Foundation::SIZE, cx, cy, Struct);
+    impl_size2d!(impl Trait<f32> for windows::Win32::Graphics::Direct2D::Common::D2D_SIZE_F, width, height, Struct);
+    impl_size2d!(impl Trait<u32> for windows::Win32::Graphics::Direct2D::Common::D2D_SIZE_U, width, height, Struct);
+}
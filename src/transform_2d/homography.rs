@@ -0,0 +1,520 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    point_2d::{Point, Point2D},
+    transform_2d::{NonAffineError, Transform2D},
+};
+use num_traits::Float;
+use std::{error::Error, fmt::Display};
+
+/// # [`Homography`]
+/// 二维射影变换（单应性变换），以 3x3 矩阵表示。
+///
+/// 与 [`Transform2D`] 相同，点被视作行向量，右乘矩阵：
+///
+/// ```text
+/// [x', y', w] = [x, y, 1] * | m11 m12 m13 |
+///                           | m21 m22 m23 |
+///                           | m31 m32 m33 |
+/// ```
+///
+/// 变换后的点为 `(x' / w, y' / w)`. 仿射变换即 `m13 = m23 = 0`, `m33 = 1` 的特例。
+///
+/// 矩阵乘以非零常数后表示同一变换，故由本类型构造的矩阵均归一化为 `m33 = 1`（`m33` 为零时除外）。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Homography<T> {
+    pub m11: T,
+    pub m12: T,
+    pub m13: T,
+    pub m21: T,
+    pub m22: T,
+    pub m23: T,
+    pub m31: T,
+    pub m32: T,
+    pub m33: T,
+}
+
+/// 矩阵不可逆，或点对退化（如三点共线）以致无法确定变换。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct SingularError;
+impl Display for SingularError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("矩阵不可逆。")
+    }
+}
+impl Error for SingularError {}
+
+impl<T> Homography<T> {
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub const fn new(
+        m11: T,
+        m12: T,
+        m13: T,
+        m21: T,
+        m22: T,
+        m23: T,
+        m31: T,
+        m32: T,
+        m33: T,
+    ) -> Self {
+        Homography {
+            m11,
+            m12,
+            m13,
+            m21,
+            m22,
+            m23,
+            m31,
+            m32,
+            m33,
+        }
+    }
+    /// 按 `[m11, m12, m13, m21, m22, m23, m31, m32, m33]` 的顺序构造。
+    #[inline]
+    pub fn from_array([m11, m12, m13, m21, m22, m23, m31, m32, m33]: [T; 9]) -> Self {
+        Homography::new(m11, m12, m13, m21, m22, m23, m31, m32, m33)
+    }
+    /// 按 `[m11, m12, m13, m21, m22, m23, m31, m32, m33]` 的顺序展开。
+    #[inline]
+    pub fn to_array(self) -> [T; 9] {
+        [
+            self.m11, self.m12, self.m13, self.m21, self.m22, self.m23, self.m31, self.m32,
+            self.m33,
+        ]
+    }
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Homography<U> {
+        Homography::from_array(self.to_array().map(f))
+    }
+}
+
+impl<T: Float> Default for Homography<T> {
+    /// 恒等变换。
+    #[inline]
+    fn default() -> Self {
+        Homography::identity()
+    }
+}
+
+impl<T: Float> From<Transform2D<T>> for Homography<T> {
+    #[inline]
+    fn from(t: Transform2D<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Homography::new(t.m11, t.m12, zero, t.m21, t.m22, zero, t.m31, t.m32, one)
+    }
+}
+impl<T: Float> TryFrom<Homography<T>> for Transform2D<T> {
+    type Error = NonAffineError;
+    /// `m13`, `m23` 不为零时不是仿射变换。
+    #[inline]
+    fn try_from(h: Homography<T>) -> Result<Self, Self::Error> {
+        if !h.m13.is_zero() || !h.m23.is_zero() || h.m33.is_zero() {
+            return Err(NonAffineError);
+        }
+        let w = h.m33;
+        Ok(Transform2D::new(
+            h.m11 / w,
+            h.m12 / w,
+            h.m21 / w,
+            h.m22 / w,
+            h.m31 / w,
+            h.m32 / w,
+        ))
+    }
+}
+
+impl<T: Float> Homography<T> {
+    /// 恒等变换。
+    #[inline]
+    pub fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Homography::new(one, zero, zero, zero, one, zero, zero, zero, one)
+    }
+    /// 将 `src` 的四个角点依次映射到 `dst` 的四个角点的变换。
+    ///
+    /// 任意三个角点共线时返回 `None`.
+    #[inline]
+    pub fn from_quad_to_quad<P, Q>(src: &[P; 4], dst: &[Q; 4]) -> Option<Self>
+    where
+        P: Point2D<T>,
+        Q: Point2D<T>,
+    {
+        Homography::estimate(src, dst)
+    }
+    /// 由至少四组对应点以最小二乘法估计变换。恰为四组时即 [`Homography::from_quad_to_quad`].
+    ///
+    /// 两组点数目不同、少于四组或退化时返回 `None`.
+    ///
+    /// 估计前会对两组点分别做归一化（平移至重心、缩放至平均距离为 √2）以改善数值稳定性，
+    /// 内部以 `f64` 计算。
+    pub fn estimate<P, Q>(src: &[P], dst: &[Q]) -> Option<Self>
+    where
+        P: Point2D<T>,
+        Q: Point2D<T>,
+    {
+        if src.len() != dst.len() || src.len() < 4 {
+            return None;
+        }
+        let to_f64 = |p: Point<&T>| Some([p.x.to_f64()?, p.y.to_f64()?]);
+        let src = src
+            .iter()
+            .map(|p| to_f64(p.as_point()))
+            .collect::<Option<Vec<_>>>()?;
+        let dst = dst
+            .iter()
+            .map(|p| to_f64(p.as_point()))
+            .collect::<Option<Vec<_>>>()?;
+        let h = estimate_f64(&src, &dst)?;
+        let h = Homography::from_array(h.map(T::from)).map(|m| m.unwrap_or_else(T::nan));
+        h.is_finite().then_some(h)
+    }
+    /// 组合两个变换：先应用 `self`, 再应用 `other`.
+    #[inline]
+    pub fn then(&self, other: &Homography<T>) -> Self {
+        let [a11, a12, a13, a21, a22, a23, a31, a32, a33] = self.to_array();
+        let [b11, b12, b13, b21, b22, b23, b31, b32, b33] = other.to_array();
+        Homography::new(
+            a11 * b11 + a12 * b21 + a13 * b31,
+            a11 * b12 + a12 * b22 + a13 * b32,
+            a11 * b13 + a12 * b23 + a13 * b33,
+            a21 * b11 + a22 * b21 + a23 * b31,
+            a21 * b12 + a22 * b22 + a23 * b32,
+            a21 * b13 + a22 * b23 + a23 * b33,
+            a31 * b11 + a32 * b21 + a33 * b31,
+            a31 * b12 + a32 * b22 + a33 * b32,
+            a31 * b13 + a32 * b23 + a33 * b33,
+        )
+        .normalized()
+    }
+    /// 矩阵的行列式。为零时变换不可逆。
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.m11 * (self.m22 * self.m33 - self.m23 * self.m32)
+            - self.m12 * (self.m21 * self.m33 - self.m23 * self.m31)
+            + self.m13 * (self.m21 * self.m32 - self.m22 * self.m31)
+    }
+    /// 逆变换，不可逆时返回 `None`.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() || !det.is_finite() {
+            return None;
+        }
+        let h = Homography::new(
+            (self.m22 * self.m33 - self.m23 * self.m32) / det,
+            (self.m13 * self.m32 - self.m12 * self.m33) / det,
+            (self.m12 * self.m23 - self.m13 * self.m22) / det,
+            (self.m23 * self.m31 - self.m21 * self.m33) / det,
+            (self.m11 * self.m33 - self.m13 * self.m31) / det,
+            (self.m13 * self.m21 - self.m11 * self.m23) / det,
+            (self.m21 * self.m32 - self.m22 * self.m31) / det,
+            (self.m12 * self.m31 - self.m11 * self.m32) / det,
+            (self.m11 * self.m22 - self.m12 * self.m21) / det,
+        );
+        Some(h.normalized())
+    }
+    /// 变换点，返回与输入相同的类型。点被映射到无穷远处时返回 `None`.
+    #[inline]
+    pub fn apply<P: Point2D<T>>(&self, p: P) -> Option<P> {
+        let Point { x, y } = p.into_point();
+        let w = x * self.m13 + y * self.m23 + self.m33;
+        if w.is_zero() {
+            return None;
+        }
+        let x_ = (x * self.m11 + y * self.m21 + self.m31) / w;
+        let y_ = (x * self.m12 + y * self.m22 + self.m32) / w;
+        (x_.is_finite() && y_.is_finite()).then(|| P::from_point(Point { x: x_, y: y_ }))
+    }
+    /// 是否为仿射变换，即 `m13`, `m23` 均为零。
+    #[inline]
+    pub fn is_affine(&self) -> bool {
+        self.m13.is_zero() && self.m23.is_zero() && !self.m33.is_zero()
+    }
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.to_array().iter().all(|m| m.is_finite())
+    }
+    /// 归一化为 `m33 = 1`, `m33` 为零时不变。
+    #[inline]
+    fn normalized(self) -> Self {
+        let w = self.m33;
+        if w.is_zero() {
+            self
+        } else {
+            self.map(|m| m / w)
+        }
+    }
+}
+
+/// 以列向量约定（`u = (h0 x + h1 y + h2) / (h6 x + h7 y + h8)`）表示的行主序矩阵。
+type Mat3 = [[f64; 3]; 3];
+
+fn mul3(a: &Mat3, b: &Mat3) -> Mat3 {
+    let mut c = [[0.0; 3]; 3];
+    for (i, row) in c.iter_mut().enumerate() {
+        for (j, c) in row.iter_mut().enumerate() {
+            *c = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    c
+}
+
+fn det3(m: &Mat3) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// 归一化后的四个点中是否有三点共线。
+fn has_collinear_triple(p: &[[f64; 2]]) -> bool {
+    [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]
+        .iter()
+        .any(|&[a, b, c]| {
+            let cross = (p[b][0] - p[a][0]) * (p[c][1] - p[a][1])
+                - (p[b][1] - p[a][1]) * (p[c][0] - p[a][0]);
+            cross.abs() <= 1e-10
+        })
+}
+
+/// 将点集平移至重心、缩放至平均距离为 √2 的相似变换及其逆变换。
+fn normalization(points: &[[f64; 2]]) -> Option<(Mat3, Mat3)> {
+    let n = points.len() as f64;
+    let cx = points.iter().map(|p| p[0]).sum::<f64>() / n;
+    let cy = points.iter().map(|p| p[1]).sum::<f64>() / n;
+    let d = points
+        .iter()
+        .map(|p| (p[0] - cx).hypot(p[1] - cy))
+        .sum::<f64>()
+        / n;
+    if !d.is_normal() {
+        return None;
+    }
+    let s = std::f64::consts::SQRT_2 / d;
+    Some((
+        [[s, 0.0, -s * cx], [0.0, s, -s * cy], [0.0, 0.0, 1.0]],
+        [[1.0 / s, 0.0, cx], [0.0, 1.0 / s, cy], [0.0, 0.0, 1.0]],
+    ))
+}
+
+/// 以部分主元高斯消元法求解 8 元线性方程组。
+///
+/// 主元相对于矩阵中最大元素过小时视作奇异，返回 `None`.
+fn solve8(mut a: [[f64; 8]; 8], mut b: [f64; 8]) -> Option<[f64; 8]> {
+    let eps = a.iter().flatten().fold(0.0, |m: f64, x| m.max(x.abs())) * 1e-12;
+    for col in 0..8 {
+        let pivot = (col..8).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() <= eps {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (pivot_row, pivot_b) = (a[col], b[col]);
+        for row in col + 1..8 {
+            let f = a[row][col] / pivot_row[col];
+            for (x, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= f * p;
+            }
+            b[row] -= f * pivot_b;
+        }
+    }
+    let mut x = [0.0; 8];
+    for row in (0..8).rev() {
+        let s: f64 = (row + 1..8).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - s) / a[row][row];
+    }
+    Some(x)
+}
+
+/// 以归一化的直接线性变换（DLT）估计，返回 [`Homography::to_array`] 顺序的矩阵。
+fn estimate_f64(src: &[[f64; 2]], dst: &[[f64; 2]]) -> Option<[f64; 9]> {
+    let (ts, _) = normalization(src)?;
+    let (td, td_inv) = normalization(dst)?;
+    let apply = |t: &Mat3, p: &[f64; 2]| [t[0][0] * p[0] + t[0][2], t[1][1] * p[1] + t[1][2]];
+    let src: Vec<_> = src.iter().map(|p| apply(&ts, p)).collect();
+    let dst: Vec<_> = dst.iter().map(|p| apply(&td, p)).collect();
+    // 恰为四组点时，任意三点共线即无解。
+    if src.len() == 4 && (has_collinear_triple(&src) || has_collinear_triple(&dst)) {
+        return None;
+    }
+    // 固定 h8 = 1, 每组点给出两个方程，以正规方程求最小二乘解。
+    let mut ata = [[0.0; 8]; 8];
+    let mut atb = [0.0; 8];
+    for (&[x, y], &[u, v]) in src.iter().zip(&dst) {
+        for (row, rhs) in [
+            ([x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y], u),
+            ([0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y], v),
+        ] {
+            for ((ata, b), &ri) in ata.iter_mut().zip(&mut atb).zip(&row) {
+                for (a, &rj) in ata.iter_mut().zip(&row) {
+                    *a += ri * rj;
+                }
+                *b += ri * rhs;
+            }
+        }
+    }
+    let h = solve8(ata, atb)?;
+    let hn = [[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h[6], h[7], 1.0]];
+    // 归一化坐标下的矩阵接近奇异时，点对是退化的。
+    let norm = hn.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+    if det3(&hn).abs() <= 1e-10 * norm * norm * norm {
+        return None;
+    }
+    let m = mul3(&mul3(&td_inv, &hn), &ts);
+    let w = if m[2][2] == 0.0 { 1.0 } else { m[2][2] };
+    // 转置为行向量约定。
+    Some([
+        m[0][0] / w,
+        m[1][0] / w,
+        m[2][0] / w,
+        m[0][1] / w,
+        m[1][1] / w,
+        m[2][1] / w,
+        m[0][2] / w,
+        m[1][2] / w,
+        m[2][2] / w,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNIT_SQUARE: [(f64, f64); 4] = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    const QUAD: [(f64, f64); 4] = [(10.0, 20.0), (110.0, 30.0), (90.0, 140.0), (-5.0, 100.0)];
+
+    fn assert_close(a: (f64, f64), b: (f64, f64), eps: f64) {
+        assert!(
+            (a.0 - b.0).abs() <= eps && (a.1 - b.1).abs() <= eps,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn unit_square_to_quad_round_trip() {
+        let h = Homography::from_quad_to_quad(&UNIT_SQUARE, &QUAD).unwrap();
+        assert!(!h.is_affine());
+        for (p, q) in UNIT_SQUARE.iter().zip(&QUAD) {
+            assert_close(h.apply(*p).unwrap(), *q, 1e-9);
+        }
+        let back = Homography::from_quad_to_quad(&QUAD, &UNIT_SQUARE).unwrap();
+        for (p, q) in QUAD.iter().zip(&UNIT_SQUARE) {
+            assert_close(back.apply(*p).unwrap(), *q, 1e-12);
+        }
+    }
+
+    #[test]
+    fn estimate_from_noisy_correspondences() {
+        let h = Homography::from_quad_to_quad(&UNIT_SQUARE, &QUAD).unwrap();
+        let src: Vec<(f64, f64)> = (0..5)
+            .flat_map(|i| (0..5).map(move |j| (i as f64 / 4.0, j as f64 / 4.0)))
+            .collect();
+        // 确定性的噪声，幅度为 ±0.01.
+        let dst: Vec<(f64, f64)> = src
+            .iter()
+            .enumerate()
+            .map(|(k, &p)| {
+                let (x, y) = h.apply(p).unwrap();
+                let noise = |s: usize| ((k * 7 + s) % 5) as f64 * 0.005 - 0.01;
+                (x + noise(0), y + noise(3))
+            })
+            .collect();
+        let estimated = Homography::estimate(&src, &dst).unwrap();
+        for p in [(0.5, 0.5), (0.1, 0.9), (0.8, 0.2)] {
+            assert_close(estimated.apply(p).unwrap(), h.apply(p).unwrap(), 0.02);
+        }
+        // 没有噪声时精确地还原。
+        let exact: Vec<(f64, f64)> = src.iter().map(|&p| h.apply(p).unwrap()).collect();
+        let estimated = Homography::estimate(&src, &exact).unwrap();
+        for p in src {
+            assert_close(estimated.apply(p).unwrap(), h.apply(p).unwrap(), 1e-9);
+        }
+        assert!(Homography::estimate(&UNIT_SQUARE[..3], &QUAD[..3]).is_none());
+        assert!(Homography::estimate(&UNIT_SQUARE, &QUAD[..3]).is_none());
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let h = Homography::from_quad_to_quad(&UNIT_SQUARE, &QUAD).unwrap();
+        let inverse = h.inverse().unwrap();
+        for p in [(0.0, 0.0), (0.25, 0.75), (2.0, -1.0), (-3.0, 0.5)] {
+            assert_close(inverse.apply(h.apply(p).unwrap()).unwrap(), p, 1e-9);
+        }
+        let identity = h.then(&inverse).to_array();
+        for (m, e) in identity
+            .iter()
+            .zip(Homography::<f64>::identity().to_array())
+        {
+            assert!((m - e).abs() < 1e-9, "{identity:?}");
+        }
+        let singular = Homography::new(1.0, 2.0, 0.0, 2.0, 4.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    fn apply_on_line_at_infinity() {
+        // w = x - 1, 直线 x = 1 被映射到无穷远处。
+        let h = Homography::new(1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0);
+        assert_eq!(h.apply((1.0, 5.0)), None);
+        assert_eq!(h.apply((1.0, -2.0)), None);
+        assert!(h.apply((2.0, 5.0)).is_some());
+    }
+
+    #[test]
+    fn degenerate_quad() {
+        let collinear = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 1.0)];
+        assert_eq!(Homography::from_quad_to_quad(&collinear, &QUAD), None);
+        assert_eq!(Homography::from_quad_to_quad(&QUAD, &collinear), None);
+        let repeated = [(0.0, 0.0), (0.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        assert_eq!(Homography::from_quad_to_quad(&UNIT_SQUARE, &repeated), None);
+        let projective = Homography::from_quad_to_quad(&UNIT_SQUARE, &QUAD).unwrap();
+        assert!(Transform2D::try_from(projective).is_err());
+    }
+
+    #[cfg(feature = "imageproc")]
+    #[test]
+    fn imageproc_projection_round_trip() {
+        use imageproc::geometric_transformations::Projection;
+        let square = UNIT_SQUARE.map(|(x, y)| (x as f32, y as f32));
+        let quad = QUAD.map(|(x, y)| (x as f32, y as f32));
+        let h = Homography::from_quad_to_quad(&square, &quad).unwrap();
+        let projection = Projection::try_from(h).unwrap();
+        for p in [(0.0f32, 0.0f32), (0.3, 0.6), (1.0, 1.0), (2.0, -1.0)] {
+            let (a, b) = (projection * p, h.apply(p).unwrap());
+            assert!(
+                (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3,
+                "{a:?} {b:?}"
+            );
+        }
+        let back = Homography::try_from(projection).unwrap();
+        for p in [(0.5f32, 0.5f32), (3.0, 2.0)] {
+            let (a, b) = (back.apply(p).unwrap(), h.apply(p).unwrap());
+            assert!(
+                (a.0 - b.0).abs() < 1e-2 && (a.1 - b.1).abs() < 1e-2,
+                "{a:?} {b:?}"
+            );
+        }
+        let singular = Homography::new(1.0f32, 2.0, 0.0, 2.0, 4.0, 0.0, 0.0, 0.0, 1.0);
+        assert!(Projection::try_from(singular).is_err());
+    }
+}
@@ -0,0 +1,250 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod homography;
+pub mod transforms;
+mod typed;
+
+pub use homography::*;
+pub use typed::*;
+
+use crate::point_2d::{Point, Point2D};
+use num_traits::{Float, Num, One, Zero};
+use std::{error::Error, fmt::Display};
+
+/// # [`Transform2D`]
+/// 二维仿射变换，以 2x3 矩阵表示。
+///
+/// 与 `euclid` 相同，点被视作行向量，右乘矩阵：
+///
+/// ```text
+/// [x', y'] = [x, y, 1] * | m11 m12 |
+///                        | m21 m22 |
+///                        | m31 m32 |
+/// ```
+///
+/// 即 `x' = x * m11 + y * m21 + m31`, `y' = x * m12 + y * m22 + m32`.
+///
+/// 因此 `a.then(&b)` 表示先应用 `a` 再应用 `b`. 与其他库的变换类型之间的转换见 [`transforms`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Transform2D<T> {
+    pub m11: T,
+    pub m12: T,
+    pub m21: T,
+    pub m22: T,
+    pub m31: T,
+    pub m32: T,
+}
+
+/// 3x3 矩阵的最后一列（以行向量约定）不是 `(0, 0, 1)` 时，无法转换为仿射变换。
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct NonAffineError;
+impl Display for NonAffineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("矩阵不是仿射变换。")
+    }
+}
+impl Error for NonAffineError {}
+
+impl<T> Transform2D<T> {
+    #[inline]
+    pub const fn new(m11: T, m12: T, m21: T, m22: T, m31: T, m32: T) -> Self {
+        Transform2D {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+        }
+    }
+    /// 按 `[m11, m12, m21, m22, m31, m32]` 的顺序构造，与 `glam` 的列主序数组相同。
+    #[inline]
+    pub fn from_array([m11, m12, m21, m22, m31, m32]: [T; 6]) -> Self {
+        Transform2D::new(m11, m12, m21, m22, m31, m32)
+    }
+    /// 按 `[m11, m12, m21, m22, m31, m32]` 的顺序展开。
+    #[inline]
+    pub fn to_array(self) -> [T; 6] {
+        [self.m11, self.m12, self.m21, self.m22, self.m31, self.m32]
+    }
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Transform2D<U> {
+        Transform2D::from_array(self.to_array().map(f))
+    }
+}
+
+impl<T: Zero + One> Default for Transform2D<T> {
+    /// 恒等变换。
+    #[inline]
+    fn default() -> Self {
+        Transform2D::identity()
+    }
+}
+
+impl<T: Zero + One> Transform2D<T> {
+    /// 恒等变换。
+    #[inline]
+    pub fn identity() -> Self {
+        Transform2D::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+    /// 平移。
+    #[inline]
+    pub fn translation(tx: T, ty: T) -> Self {
+        Transform2D::new(T::one(), T::zero(), T::zero(), T::one(), tx, ty)
+    }
+    /// 以原点为中心缩放。
+    #[inline]
+    pub fn scale(sx: T, sy: T) -> Self {
+        Transform2D::new(sx, T::zero(), T::zero(), sy, T::zero(), T::zero())
+    }
+    /// 错切，即 `x' = x + kx * y`, `y' = ky * x + y`.
+    #[inline]
+    pub fn shear(kx: T, ky: T) -> Self {
+        Transform2D::new(T::one(), ky, kx, T::one(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Float> Transform2D<T> {
+    /// 绕原点旋转，角度以弧度表示，在 y 轴向上的坐标系中为逆时针方向。
+    #[inline]
+    pub fn rotation(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Transform2D::new(cos, sin, -sin, cos, T::zero(), T::zero())
+    }
+    /// 以过原点、与 x 轴夹角为 `angle`（弧度）的直线为轴镜像。
+    #[inline]
+    pub fn reflection(angle: T) -> Self {
+        let (sin, cos) = (angle + angle).sin_cos();
+        Transform2D::new(cos, sin, sin, -cos, T::zero(), T::zero())
+    }
+    /// 先应用该变换，再绕原点旋转。
+    #[inline]
+    pub fn then_rotate(&self, angle: T) -> Self {
+        self.then(&Transform2D::rotation(angle))
+    }
+}
+
+impl<T: Copy + Num> Transform2D<T> {
+    /// 以 x 轴为轴镜像，即 `y' = -y`.
+    #[inline]
+    pub fn reflection_x() -> Self {
+        Transform2D::scale(T::one(), T::zero() - T::one())
+    }
+    /// 以 y 轴为轴镜像，即 `x' = -x`.
+    #[inline]
+    pub fn reflection_y() -> Self {
+        Transform2D::scale(T::zero() - T::one(), T::one())
+    }
+    /// 组合两个变换：先应用 `self`, 再应用 `other`.
+    #[inline]
+    pub fn then(&self, other: &Transform2D<T>) -> Self {
+        Transform2D::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+    /// 先应用该变换，再平移。
+    #[inline]
+    pub fn then_translate(&self, tx: T, ty: T) -> Self {
+        Transform2D::new(
+            self.m11,
+            self.m12,
+            self.m21,
+            self.m22,
+            self.m31 + tx,
+            self.m32 + ty,
+        )
+    }
+    /// 先应用该变换，再以原点为中心缩放。
+    #[inline]
+    pub fn then_scale(&self, sx: T, sy: T) -> Self {
+        self.then(&Transform2D::scale(sx, sy))
+    }
+    /// 线性部分的行列式。为零时变换不可逆，为负时变换会翻转图形的朝向。
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+    /// 逆变换，不可逆时返回 `None`.
+    ///
+    /// 对于整数类型，仅当行列式为 `±1` 时结果是精确的。
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        let zero = T::zero();
+        Some(Transform2D::new(
+            self.m22 / det,
+            (zero - self.m12) / det,
+            (zero - self.m21) / det,
+            self.m11 / det,
+            (self.m21 * self.m32 - self.m22 * self.m31) / det,
+            (self.m31 * self.m12 - self.m11 * self.m32) / det,
+        ))
+    }
+    /// 变换点，返回与输入相同的类型。
+    #[inline]
+    pub fn transform_point<P: Point2D<T>>(&self, p: P) -> P {
+        let Point { x, y } = p.into_point();
+        P::from_point(Point {
+            x: x * self.m11 + y * self.m21 + self.m31,
+            y: x * self.m12 + y * self.m22 + self.m32,
+        })
+    }
+    /// 变换向量，即只应用线性部分，忽略平移。返回与输入相同的类型。
+    #[inline]
+    pub fn transform_vector<P: Point2D<T>>(&self, v: P) -> P {
+        let Point { x, y } = v.into_point();
+        P::from_point(Point {
+            x: x * self.m11 + y * self.m21,
+            y: x * self.m12 + y * self.m22,
+        })
+    }
+}
+
+impl<T: Zero + One + PartialEq> Transform2D<T> {
+    /// 是否为恒等变换。
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.m11.is_one()
+            && self.m12.is_zero()
+            && self.m21.is_zero()
+            && self.m22.is_one()
+            && self.m31.is_zero()
+            && self.m32.is_zero()
+    }
+}
@@ -0,0 +1,164 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 内建 [`Transform2D`](crate::transform_2d::Transform2D) 与其他库的变换类型之间的转换。
+//!
+//! 这些变换类型均可无损地转换为 `Transform2D`, 反之亦然；
+//! 3x3 矩阵只有在表示仿射变换时才能转换为 `Transform2D`, 故使用 [`TryFrom`].
+
+#[allow(unused_imports)]
+use crate::transform_2d::{
+    Homography, NonAffineError, SingularError, Transform2D, TypedTransform2D,
+};
+
+#[cfg(feature = "euclid")]
+impl<T, Src, Dst> From<euclid::Transform2D<T, Src, Dst>> for Transform2D<T> {
+    #[inline]
+    fn from(t: euclid::Transform2D<T, Src, Dst>) -> Self {
+        Transform2D::new(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+    }
+}
+#[cfg(feature = "euclid")]
+impl<T, Src, Dst> From<Transform2D<T>> for euclid::Transform2D<T, Src, Dst> {
+    #[inline]
+    fn from(t: Transform2D<T>) -> Self {
+        euclid::Transform2D::new(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+    }
+}
+/// 保留 `euclid` 的单位参数。
+#[cfg(feature = "euclid")]
+impl<T, Src, Dst> From<euclid::Transform2D<T, Src, Dst>> for TypedTransform2D<T, Src, Dst> {
+    #[inline]
+    fn from(t: euclid::Transform2D<T, Src, Dst>) -> Self {
+        TypedTransform2D::from_untyped(t.into())
+    }
+}
+#[cfg(feature = "euclid")]
+impl<T, Src, Dst> From<TypedTransform2D<T, Src, Dst>> for euclid::Transform2D<T, Src, Dst> {
+    #[inline]
+    fn from(t: TypedTransform2D<T, Src, Dst>) -> Self {
+        t.to_untyped().into()
+    }
+}
+
+/// `glam` 的矩阵是列主序的，其列向量依次为 `(m11, m12)`, `(m21, m22)`, `(m31, m32)`.
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_transform {
+    ($t:ty, $affine:ty, $mat3:ty) => {
+        impl From<$affine> for Transform2D<$t> {
+            #[inline]
+            fn from(t: $affine) -> Self {
+                Transform2D::from_array(t.to_cols_array())
+            }
+        }
+        impl From<Transform2D<$t>> for $affine {
+            #[inline]
+            fn from(t: Transform2D<$t>) -> Self {
+                <$affine>::from_cols_array(&t.to_array())
+            }
+        }
+        impl From<Transform2D<$t>> for $mat3 {
+            #[inline]
+            fn from(t: Transform2D<$t>) -> Self {
+                <$mat3>::from_cols_array(&[t.m11, t.m12, 0.0, t.m21, t.m22, 0.0, t.m31, t.m32, 1.0])
+            }
+        }
+        impl TryFrom<$mat3> for Transform2D<$t> {
+            type Error = NonAffineError;
+            #[inline]
+            fn try_from(m: $mat3) -> Result<Self, Self::Error> {
+                let [m11, m12, m13, m21, m22, m23, m31, m32, m33] = m.to_cols_array();
+                if m13 == 0.0 && m23 == 0.0 && m33 == 1.0 {
+                    Ok(Transform2D::new(m11, m12, m21, m22, m31, m32))
+                } else {
+                    Err(NonAffineError)
+                }
+            }
+        }
+    };
+}
+#[cfg(feature = "glam")]
+impl_glam_transform!(f32, glam::Affine2, glam::Mat3);
+#[cfg(feature = "glam")]
+impl_glam_transform!(f64, glam::DAffine2, glam::DMat3);
+
+#[cfg(feature = "core-graphics")]
+use core_graphics::{base::CGFloat, geometry::CGAffineTransform};
+/// `CGAffineTransform` 中 `a, b, c, d, tx, ty` 依次对应 `m11, m12, m21, m22, m31, m32`.
+#[cfg(feature = "core-graphics")]
+impl From<CGAffineTransform> for Transform2D<CGFloat> {
+    #[inline]
+    fn from(t: CGAffineTransform) -> Self {
+        Transform2D::new(t.a, t.b, t.c, t.d, t.tx, t.ty)
+    }
+}
+#[cfg(feature = "core-graphics")]
+impl From<Transform2D<CGFloat>> for CGAffineTransform {
+    #[inline]
+    fn from(t: Transform2D<CGFloat>) -> Self {
+        CGAffineTransform::new(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+    }
+}
+
+/// `ttf_parser::Transform` 中 `a, b, c, d, e, f` 依次对应 `m11, m12, m21, m22, m31, m32`.
+#[cfg(feature = "ttf-parser")]
+impl From<ttf_parser::Transform> for Transform2D<f32> {
+    #[inline]
+    fn from(t: ttf_parser::Transform) -> Self {
+        Transform2D::new(t.a, t.b, t.c, t.d, t.e, t.f)
+    }
+}
+#[cfg(feature = "ttf-parser")]
+impl From<Transform2D<f32>> for ttf_parser::Transform {
+    #[inline]
+    fn from(t: Transform2D<f32>) -> Self {
+        ttf_parser::Transform::new(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+    }
+}
+
+/// `imageproc` 以列向量约定的行主序数组表示矩阵，即 [`Homography`] 矩阵的转置。
+///
+/// `Projection` 要求矩阵可逆，不可逆时返回 [`SingularError`].
+#[cfg(feature = "imageproc")]
+impl TryFrom<Homography<f32>> for imageproc::geometric_transformations::Projection {
+    type Error = SingularError;
+    #[inline]
+    fn try_from(h: Homography<f32>) -> Result<Self, Self::Error> {
+        imageproc::geometric_transformations::Projection::from_matrix([
+            h.m11, h.m21, h.m31, h.m12, h.m22, h.m32, h.m13, h.m23, h.m33,
+        ])
+        .ok_or(SingularError)
+    }
+}
+/// `Projection` 不提供读取矩阵的方法，故由单位正方形四个角点的像重新求出矩阵。
+///
+/// 角点被映射到无穷远处时无法求出，返回 [`SingularError`].
+#[cfg(feature = "imageproc")]
+impl TryFrom<imageproc::geometric_transformations::Projection> for Homography<f32> {
+    type Error = SingularError;
+    #[inline]
+    fn try_from(p: imageproc::geometric_transformations::Projection) -> Result<Self, Self::Error> {
+        let src = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let dst = src.map(|q| p * q);
+        Homography::from_quad_to_quad(&src, &dst).ok_or(SingularError)
+    }
+}
@@ -0,0 +1,129 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    point_2d::{TypedPoint, TypedPoint2D},
+    transform_2d::Transform2D,
+};
+use num_traits::Num;
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// # [`TypedTransform2D`]
+/// 从坐标空间 `Src` 到坐标空间 `Dst` 的 [`Transform2D`].
+///
+/// 只接受 `Src` 空间中的点，并得到 `Dst` 空间中的点；组合时要求前一变换的目标空间与后一变换的源空间相同。
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct TypedTransform2D<T, Src, Dst> {
+    pub transform: Transform2D<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _unit: PhantomData<(Src, Dst)>,
+}
+
+impl<T, Src, Dst> TypedTransform2D<T, Src, Dst> {
+    /// 为无单位的变换标记坐标空间。
+    #[inline]
+    pub const fn from_untyped(transform: Transform2D<T>) -> Self {
+        TypedTransform2D {
+            transform,
+            _unit: PhantomData,
+        }
+    }
+    /// 去掉坐标空间标记。
+    #[inline]
+    pub fn to_untyped(self) -> Transform2D<T> {
+        self.transform
+    }
+    /// 显式地改为另一对坐标空间，矩阵不变。
+    #[inline]
+    pub fn cast_unit<Src2, Dst2>(self) -> TypedTransform2D<T, Src2, Dst2> {
+        TypedTransform2D::from_untyped(self.transform)
+    }
+}
+
+impl<T: Copy + Num, U> TypedTransform2D<T, U, U> {
+    /// 同一坐标空间中的恒等变换。
+    #[inline]
+    pub fn identity() -> Self {
+        TypedTransform2D::from_untyped(Transform2D::identity())
+    }
+}
+
+impl<T: Copy + Num, Src, Dst> TypedTransform2D<T, Src, Dst> {
+    /// 组合两个变换：先应用 `self`, 再应用 `other`.
+    #[inline]
+    pub fn then<Dst2>(
+        &self,
+        other: &TypedTransform2D<T, Dst, Dst2>,
+    ) -> TypedTransform2D<T, Src, Dst2> {
+        TypedTransform2D::from_untyped(self.transform.then(&other.transform))
+    }
+    /// 逆变换，不可逆时返回 `None`. 见 [`Transform2D::inverse`].
+    #[inline]
+    pub fn inverse(&self) -> Option<TypedTransform2D<T, Dst, Src>> {
+        self.transform.inverse().map(TypedTransform2D::from_untyped)
+    }
+    /// 将 `Src` 空间中的点变换到 `Dst` 空间。
+    #[inline]
+    pub fn transform_point<P: TypedPoint2D<T, Src>>(&self, p: P) -> TypedPoint<T, Dst> {
+        let p = p.into_typed_point();
+        TypedPoint::from_untyped(self.transform.transform_point(p.to_untyped()))
+    }
+}
+
+impl<T, Src, Dst> From<Transform2D<T>> for TypedTransform2D<T, Src, Dst> {
+    #[inline]
+    fn from(transform: Transform2D<T>) -> Self {
+        TypedTransform2D::from_untyped(transform)
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for TypedTransform2D<T, Src, Dst> {}
+impl<T: Clone, Src, Dst> Clone for TypedTransform2D<T, Src, Dst> {
+    #[inline]
+    fn clone(&self) -> Self {
+        TypedTransform2D::from_untyped(self.transform.clone())
+    }
+}
+impl<T: PartialEq, Src, Dst> PartialEq for TypedTransform2D<T, Src, Dst> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.transform == other.transform
+    }
+}
+impl<T: Eq, Src, Dst> Eq for TypedTransform2D<T, Src, Dst> {}
+impl<T: Hash, Src, Dst> Hash for TypedTransform2D<T, Src, Dst> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.transform.hash(state);
+    }
+}
+impl<T: Debug, Src, Dst> Debug for TypedTransform2D<T, Src, Dst> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedTransform2D")
+            .field("transform", &self.transform)
+            .finish()
+    }
+}
@@ -0,0 +1,65 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#![cfg(feature = "derive")]
+
+use std::marker::PhantomData;
+use yapt::point_2d::{Point, Point2D, Point2DMut};
+
+#[derive(Point2D, Debug, PartialEq)]
+struct Typed<T, U> {
+    x: T,
+    y: T,
+    _unit: PhantomData<U>,
+}
+
+#[derive(Point2D, Debug, PartialEq)]
+#[point2d(x = "2", y = "0")]
+struct Tagged<'a, T: Copy>(T, &'a str, T)
+where
+    T: Default;
+
+impl Default for Tagged<'_, i32> {
+    fn default() -> Self {
+        Tagged(0, "", 0)
+    }
+}
+
+#[test]
+fn extra_fields_are_defaulted() {
+    struct Meters;
+    let p = Typed::<f64, Meters>::from_point(Point::new(1.0, 2.0));
+    assert_eq!((p.x, p.y), (1.0, 2.0));
+    assert_eq!(p.into_point(), Point::new(1.0, 2.0));
+
+    let mut t = Tagged(3, "tag", 4);
+    assert_eq!((*t.rx(), *t.ry()), (4, 3));
+    *t.as_point_mut().x = 5;
+    assert_eq!(t, Tagged(3, "tag", 5));
+    assert_eq!(Tagged::from_point(Point::new(1, 2)), Tagged(2, "", 1));
+}
+
+#[test]
+fn compile_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
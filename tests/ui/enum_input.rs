@@ -0,0 +1,9 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+enum Either {
+    X(i32),
+    Y(i32),
+}
+
+fn main() {}
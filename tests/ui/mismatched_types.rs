@@ -0,0 +1,9 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+struct Mixed {
+    x: i32,
+    y: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+#[point2d(x = "col", y = "line")]
+struct Cell {
+    col: i32,
+    row: i32,
+}
+
+fn main() {}
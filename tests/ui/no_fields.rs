@@ -0,0 +1,6 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+struct Empty {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+struct OnlyX {
+    x: i32,
+}
+
+fn main() {}
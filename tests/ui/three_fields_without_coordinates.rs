@@ -0,0 +1,10 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+union Bits {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use yapt::point_2d::Point2D;
+
+#[derive(Point2D)]
+#[point2d(x = "col", z = "depth")]
+struct Cell {
+    col: i32,
+    row: i32,
+    depth: i32,
+}
+
+fn main() {}
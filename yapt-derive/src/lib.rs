@@ -0,0 +1,229 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 为 [`yapt`](https://docs.rs/yapt) 提供派生宏。
+//!
+//! 请通过 `yapt` 的 `derive` feature 使用，而不是直接依赖本 crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Index, LitStr, Member,
+    Result,
+};
+
+/// 为结构体实现 `yapt::point_2d::Point2D` 与 `yapt::point_2d::Point2DMut`.
+///
+/// 结构体必须恰好有两个坐标字段，且两者类型相同。
+///
+/// 具名字段默认使用 `x` 和 `y`, 元组结构体默认使用 `0` 和 `1`.
+/// 可以通过 `#[point2d(x = "col", y = "row")]` 指定其他字段，
+/// 元组结构体则写作 `#[point2d(x = "1", y = "0")]`.
+///
+/// 坐标以外的字段（如 `PhantomData<U>` 单位参数）在 `from_point` 中以 `Default::default()` 构造。
+#[proc_macro_derive(Point2D, attributes(point2d))]
+pub fn derive_point_2d(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_point_2d(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct CoordinateFields {
+    x: Option<LitStr>,
+    y: Option<LitStr>,
+}
+
+impl CoordinateFields {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut fields = CoordinateFields { x: None, y: None };
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("point2d")) {
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("x") {
+                    &mut fields.x
+                } else if meta.path.is_ident("y") {
+                    &mut fields.y
+                } else {
+                    return Err(meta.error("未知的 `point2d` 参数，只支持 `x` 和 `y`"));
+                };
+                if slot.is_some() {
+                    return Err(meta.error("重复指定了坐标字段"));
+                }
+                *slot = Some(meta.value()?.parse()?);
+                Ok(())
+            })?;
+        }
+        Ok(fields)
+    }
+}
+
+fn resolve_member(
+    fields: &Fields,
+    name: Option<&LitStr>,
+    default: Member,
+) -> Result<(Member, syn::Type)> {
+    let (member, span) = match name {
+        Some(lit) => {
+            let member = match fields {
+                Fields::Unnamed(_) => lit
+                    .value()
+                    .parse::<u32>()
+                    .map(|index| {
+                        Member::Unnamed(Index {
+                            index,
+                            span: lit.span(),
+                        })
+                    })
+                    .map_err(|_| {
+                        Error::new(lit.span(), "元组结构体的坐标字段应当是下标，如 \"0\"")
+                    })?,
+                _ => Member::Named(lit.parse()?),
+            };
+            (member, lit.span())
+        }
+        None => (default, Span::call_site()),
+    };
+    let ty = match (fields, &member) {
+        (Fields::Named(named), Member::Named(ident)) => named
+            .named
+            .iter()
+            .find(|f| f.ident.as_ref() == Some(ident))
+            .map(|f| f.ty.clone()),
+        (Fields::Unnamed(unnamed), Member::Unnamed(index)) => unnamed
+            .unnamed
+            .iter()
+            .nth(index.index as usize)
+            .map(|f| f.ty.clone()),
+        _ => None,
+    };
+    match ty {
+        Some(ty) => Ok((member, ty)),
+        None => Err(Error::new(
+            span,
+            format!(
+                "找不到坐标字段 `{}`, 可以通过 `#[point2d(x = \"..\", y = \"..\")]` 指定",
+                member.to_token_stream()
+            ),
+        )),
+    }
+}
+
+fn expand_point_2d(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span(),
+                "`Point2D` 只能为结构体派生",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "`Point2D` 只能为结构体派生",
+            ))
+        }
+    };
+    if fields.len() < 2 {
+        return Err(Error::new(
+            if fields.is_empty() {
+                input.ident.span()
+            } else {
+                fields.span()
+            },
+            format!(
+                "`Point2D` 要求结构体恰好有两个坐标字段，但只找到了 {} 个字段",
+                fields.len()
+            ),
+        ));
+    }
+    let attr = CoordinateFields::parse(&input)?;
+    let (default_x, default_y) = match fields {
+        Fields::Unnamed(_) => (
+            Member::Unnamed(Index::from(0)),
+            Member::Unnamed(Index::from(1)),
+        ),
+        _ => (
+            Member::Named(syn::Ident::new("x", Span::call_site())),
+            Member::Named(syn::Ident::new("y", Span::call_site())),
+        ),
+    };
+    let (x, x_ty) = resolve_member(fields, attr.x.as_ref(), default_x)?;
+    let (y, y_ty) = resolve_member(fields, attr.y.as_ref(), default_y)?;
+    if x == y {
+        return Err(Error::new(
+            attr.y.as_ref().map_or_else(Span::call_site, LitStr::span),
+            "x 与 y 不能是同一个字段",
+        ));
+    }
+    if x_ty.to_token_stream().to_string() != y_ty.to_token_stream().to_string() {
+        return Err(Error::new(
+            y_ty.span(),
+            "`Point2D` 要求 x 与 y 坐标字段的类型相同",
+        ));
+    }
+
+    let others = fields
+        .members()
+        .filter(|member| *member != x && *member != y);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let ty = &x_ty;
+    Ok(quote! {
+        impl #impl_generics ::yapt::point_2d::Point2D<#ty> for #name #ty_generics #where_clause {
+            #[inline]
+            fn rx(&self) -> &#ty {
+                &self.#x
+            }
+            #[inline]
+            fn ry(&self) -> &#ty {
+                &self.#y
+            }
+            #[inline]
+            fn into_point(self) -> ::yapt::point_2d::Point<#ty> {
+                ::yapt::point_2d::Point {
+                    x: self.#x,
+                    y: self.#y,
+                }
+            }
+            #[inline]
+            fn from_point(p: ::yapt::point_2d::Point<#ty>) -> Self {
+                Self {
+                    #x: p.x,
+                    #y: p.y,
+                    #(#others: ::core::default::Default::default(),)*
+                }
+            }
+        }
+        impl #impl_generics ::yapt::point_2d::Point2DMut<#ty> for #name #ty_generics #where_clause {
+            #[inline]
+            fn as_point_mut(&mut self) -> ::yapt::point_2d::Point<&mut #ty> {
+                ::yapt::point_2d::Point {
+                    x: &mut self.#x,
+                    y: &mut self.#y,
+                }
+            }
+        }
+    })
+}